@@ -0,0 +1,130 @@
+//! Items related to searching a **Store** for the predecessor of some key bytes.
+//!
+//! A **Store** may only be scanned in ascending order, so the predecessor of a key is found by
+//! performing a series of scans that narrow down the greatest preceding key one byte at a time.
+
+use {Result, Store};
+
+/// Find the greatest entry within the `tree` whose key precedes the given `key` bytes.
+///
+/// If `inclusive` is `true` and an entry exists for `key`, that entry is returned.
+///
+/// The number of scans performed is roughly proportional to the length of the returned key.
+pub fn find_pred<S>(
+    tree: &S,
+    key: &[u8],
+    inclusive: bool,
+) -> Result<Option<(Vec<u8>, Vec<u8>)>>
+where
+    S: ?Sized + Store,
+{
+    if inclusive {
+        if let Some(value) = tree.get(key)? {
+            return Ok(Some((key.to_vec(), value)));
+        }
+    }
+
+    // If the first key does not precede `key`, there is no predecessor.
+    match first(tree, &[])? {
+        Some((ref k, _)) if &k[..] < key => (),
+        _ => return Ok(None),
+    }
+
+    // Find the length of the longest prefix of `key` that is shared with some preceding key.
+    let (mut shared, mut unshared) = (0, key.len());
+    while unshared - shared > 1 {
+        let mid = shared + (unshared - shared) / 2;
+        match first(tree, &key[..mid])? {
+            Some((ref k, _)) if &k[..] < key => shared = mid,
+            _ => unshared = mid,
+        }
+    }
+
+    // Find the greatest byte less than `key[shared]` that follows the shared prefix.
+    let mut prefix = key[..shared].to_vec();
+    let byte = greatest_byte(key[shared] as u16, |b| {
+        prefix.push(b);
+        let entry = first(tree, &prefix)?;
+        prefix.pop();
+        Ok(match entry {
+            Some((ref k, _)) => &k[..] < key,
+            None => false,
+        })
+    })?;
+    match byte {
+        // The shared prefix itself must be the predecessor.
+        None => return first(tree, &prefix),
+        Some(b) => prefix.push(b),
+    }
+
+    find_last_with_prefix(tree, prefix)
+}
+
+/// Find the greatest entry within the `tree` whose key begins with the given `prefix` bytes.
+///
+/// The number of scans performed is roughly proportional to the length of the returned key.
+pub fn find_last<S>(tree: &S, prefix: &[u8]) -> Result<Option<(Vec<u8>, Vec<u8>)>>
+where
+    S: ?Sized + Store,
+{
+    match first(tree, prefix)? {
+        Some((ref k, _)) if k.starts_with(prefix) => (),
+        _ => return Ok(None),
+    }
+    find_last_with_prefix(tree, prefix.to_vec())
+}
+
+// Find the greatest key that starts with the prefix, given that at least one such key exists.
+fn find_last_with_prefix<S>(tree: &S, mut prefix: Vec<u8>) -> Result<Option<(Vec<u8>, Vec<u8>)>>
+where
+    S: ?Sized + Store,
+{
+    loop {
+        let byte = greatest_byte(256, |b| {
+            prefix.push(b);
+            let entry = first(tree, &prefix)?;
+            prefix.pop();
+            Ok(match entry {
+                Some((ref k, _)) => k.starts_with(&prefix),
+                None => false,
+            })
+        })?;
+        match byte {
+            None => return first(tree, &prefix),
+            Some(b) => prefix.push(b),
+        }
+    }
+}
+
+// The first entry within the tree that is greater than or equal to the given key.
+fn first<S>(tree: &S, key: &[u8]) -> Result<Option<(Vec<u8>, Vec<u8>)>>
+where
+    S: ?Sized + Store,
+{
+    match tree.scan(key).next() {
+        None => Ok(None),
+        Some(Err(err)) => Err(err),
+        Some(Ok(kv)) => Ok(Some(kv)),
+    }
+}
+
+// Find the greatest byte within `0..end` for which `f` returns `true`.
+//
+// `f` must return `true` for all bytes up to some point and `false` for all bytes after it.
+fn greatest_byte<F>(end: u16, mut f: F) -> Result<Option<u8>>
+where
+    F: FnMut(u8) -> Result<bool>,
+{
+    if end == 0 || !f(0)? {
+        return Ok(None);
+    }
+    let (mut lo, mut hi) = (0, end);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        match f(mid as u8)? {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    Ok(Some(lo as u8))
+}
@@ -0,0 +1,38 @@
+//! Items related to encoding values to and from the bytes stored within the tree.
+
+use {Result, Table};
+
+/// A method of encoding values to and from bytes.
+///
+/// Each **Table** selects its codec via its `Codec` associated type. As this is resolved per table,
+/// tables within the same tree may each use a different codec.
+pub trait ValueCodec {
+    /// Encode the given value of table `T` as bytes.
+    fn encode<T>(value: &T::Value) -> Result<Vec<u8>>
+    where
+        T: ?Sized + Table;
+    /// Decode a value of table `T` from the given bytes.
+    fn decode<T>(bytes: &[u8]) -> Result<T::Value>
+    where
+        T: ?Sized + Table;
+}
+
+/// The default codec, encoding values via `bincode` using the table's `bincode_config`.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Bincode;
+
+impl ValueCodec for Bincode {
+    fn encode<T>(value: &T::Value) -> Result<Vec<u8>>
+    where
+        T: ?Sized + Table,
+    {
+        Ok(T::bincode_config().serialize(value)?)
+    }
+
+    fn decode<T>(bytes: &[u8]) -> Result<T::Value>
+    where
+        T: ?Sized + Table,
+    {
+        Ok(T::bincode_config().deserialize(bytes)?)
+    }
+}
@@ -1,21 +1,36 @@
 //! A wrapper around `&sled::Tree` which provides an API around a single **Table** within the tree.
 
-extern crate bincode;
+pub extern crate bincode;
 extern crate bytekey;
+#[cfg(feature = "checksum")] extern crate crc32fast;
+#[cfg(feature = "async")] extern crate futures;
+#[cfg(all(debug_assertions, feature = "registry"))] #[macro_use] extern crate lazy_static;
 extern crate serde;
 #[macro_use] extern crate serde_derive;
+#[cfg(feature = "rand")] extern crate rand;
 pub extern crate sled;
+#[cfg(feature = "compress")] extern crate zstd;
 
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::error::Error as StdError;
-use std::{fmt, ops};
+use std::{cmp, fmt, io, ops};
 use std::marker::PhantomData;
+use std::sync::Arc;
+use store::StoreIter;
 use unsigned_binary_search::UnsignedBinarySearchKey;
 
+pub use self::codec::{Bincode, ValueCodec};
 pub use self::reversible::Reversible;
-pub use self::timestamp::{Timestamp, Timestamped};
+pub use self::store::{MemStore, Store};
+pub use self::timestamp::{SetTimestamp, Timestamp, Timestamped, TimestampedExplicit, Ttl};
 
+pub mod byte_search;
+pub mod codec;
+#[cfg(all(debug_assertions, feature = "registry"))] mod registry;
 pub mod reversible;
+pub mod store;
 pub mod timestamp;
 pub mod unsigned_binary_search;
 
@@ -27,8 +42,75 @@ pub trait Table {
     type Key: Key;
     /// The type used as the value associated with a key.
     type Value: Value;
+    /// The codec with which values are encoded to and decoded from the bytes within the tree.
+    ///
+    /// Most tables will want the `Bincode` codec, which may be configured via `bincode_config`.
+    /// As associated type defaults are not yet stable, this must be specified even so.
+    type Codec: ValueCodec;
     /// A constant, unique identifier that distinguishes the table from all others at runtime.
+    ///
+    /// The `ID` is serialized once when constructing a **Reader** or **Writer**, which panics if
+    /// `bytekey` cannot serialize it.
+    ///
+    /// In debug builds with the default `registry` feature, constructing a **Reader** or **Writer**
+    /// panics if a different table is in use within the same store whose serialized `ID` is equal
+    /// to, or a prefix of, this table's or vice versa.
     const ID: Self::Id;
+    /// Whether or not values should be compressed with `zstd` before being written to the tree.
+    ///
+    /// Only the value bytes are compressed, so the ordering of keys is unaffected.
+    #[cfg(feature = "compress")]
+    const COMPRESS: bool = false;
+    /// Whether or not a CRC32 checksum should be appended to each value written to the tree.
+    ///
+    /// When enabled, the checksum is verified each time a value is read, producing an
+    /// `Error::ChecksumMismatch` if the stored bytes have been corrupted.
+    ///
+    /// **Note:** Checksums must be enabled before the first value is written to the table, as
+    /// values written without a checksum cannot be distinguished from those written with one.
+    #[cfg(feature = "checksum")]
+    const CHECKSUM: bool = false;
+    /// The schema version of `Self::Value`, if the values within the table should be versioned.
+    ///
+    /// When `Some`, each value is written with a leading version byte. Values that are read with
+    /// any other version are passed to `migrate` in order to produce the current `Value`.
+    ///
+    /// **Note:** Versioning must be enabled before the first value is written to the table, as
+    /// unversioned values cannot be distinguished from versioned ones.
+    const VERSION: Option<u8> = None;
+
+    /// Produce a `Value` from the bytes of a value that was written under some other schema
+    /// `version`.
+    ///
+    /// The given `bytes` exclude the leading version byte. By default, this assumes the layout of
+    /// the value is unchanged and simply decodes the bytes.
+    fn migrate(version: u8, bytes: &[u8]) -> Result<Self::Value> {
+        let _ = version;
+        Self::Codec::decode::<Self>(bytes)
+    }
+
+    /// The configuration with which values are encoded and decoded by the `Bincode` codec.
+    ///
+    /// By default, this is bincode's default configuration, which imposes no size limit. Tables
+    /// may set a `limit` in order to reject values whose encoding would exceed it upon writing.
+    ///
+    /// **Note:** Changing the endianness changes the layout of stored values, so this must be
+    /// chosen before the first value is written to the table.
+    fn bincode_config() -> bincode::Config {
+        bincode::config()
+    }
+
+    /// Combine an `operand` with the `existing` value for some key, as performed by
+    /// `Writer::merge`.
+    ///
+    /// Returning `None` removes the entry. By default, the operand simply replaces the existing
+    /// value.
+    ///
+    /// **Note:** This is only called if `merge_operator::<Self>` is registered with the tree.
+    fn merge(existing: Option<Self::Value>, operand: Self::Value) -> Option<Self::Value> {
+        let _ = existing;
+        Some(operand)
+    }
 }
 
 /// Types that may be used as a **Id** to distinguish a **Table** from others.
@@ -41,24 +123,68 @@ pub trait Key: for<'de> Deserialize<'de> + Serialize {}
 pub trait Value: for<'de> Deserialize<'de> + Serialize {}
 
 /// **Read-only** access to a **Table** within a **sled::Tree**.
+///
+/// The table may be kept within any other **Store** via the `S` parameter.
 #[derive(Debug)]
-pub struct Reader<'a, T> {
-    tree: &'a sled::Tree,
+pub struct Reader<'a, T, S: 'a = sled::Tree> {
+    tree: &'a S,
+    // The serialized `T::ID` that prefixes every key, cached so that it is neither re-serialized
+    // nor copied for each key or iterator.
+    id_bytes: Arc<[u8]>,
+    // Releases the table's ID within the store's registry when the reader is dropped.
+    #[cfg(all(debug_assertions, feature = "registry"))]
+    registration: registry::Registration,
     _table: PhantomData<T>,
 }
 
 /// Read and write access to a **Table** within a **sled::Tree**.
+///
+/// The table may be kept within any other **Store** via the `S` parameter.
 #[derive(Debug)]
-pub struct Writer<'a, T> {
-    reader: Reader<'a, T>,
+pub struct Writer<'a, T, S: 'a = sled::Tree> {
+    reader: Reader<'a, T, S>,
 }
 
 /// An iterator yielding key/value pairs from a table of type `T`.
+///
+/// The `size_hint` only reflects the upper bound provided by the store's own iterator, as the
+/// number of entries within a table is not known without scanning it. A **sled::Tree** provides no
+/// such bound, so the hint is `(0, None)` for tables within a tree.
 pub struct Iter<'a, T> {
     iter_bytes: IterBytes<'a>,
     _table: PhantomData<T>,
 }
 
+/// An iterator yielding key/value pairs from a table of type `T` that may look ahead by one entry
+/// via `peek`.
+pub struct PeekableIter<'a, T>
+where
+    T: Table,
+{
+    iter: Iter<'a, T>,
+    // The entry read by `peek` that has not yet been yielded, where `Some(None)` marks the end.
+    peeked: Option<Option<Result<(T::Key, T::Value)>>>,
+}
+
+/// An iterator yielding key/value pairs from a table of type `T`, produced by iterating over a
+/// `&Reader` via `IntoIterator`.
+///
+/// As beginning iteration is fallible, any error that occurs while doing so is yielded as the
+/// first and only item.
+pub struct IntoIter<'a, T> {
+    // The error that occurred while beginning iteration, until it has been yielded.
+    iter: std::result::Result<Iter<'a, T>, Option<Error>>,
+}
+
+/// A stream yielding key/value pairs from a table of type `T`.
+///
+/// Each entry is read inline by the blocking `Iter` upon `poll`, so the stream is always ready.
+/// Services that must not block their executor should poll the stream on a dedicated thread.
+#[cfg(feature = "async")]
+pub struct TableStream<'a, T> {
+    iter: Iter<'a, T>,
+}
+
 /// An iterator yielding the byte representation of key/value pairs from a table of type `T`.
 ///
 /// The yielded bytes for each entry are laid out as follows:
@@ -67,8 +193,114 @@ pub struct Iter<'a, T> {
 /// ([T::ID, T::Key], T::Value)
 /// ```
 pub struct IterBytes<'a> {
-    id_bytes: Vec<u8>,
-    iter: sled::Iter<'a>,
+    id_bytes: Arc<[u8]>,
+    // The bytes following `id_bytes` with which all yielded keys must begin.
+    prefix_bytes: Vec<u8>,
+    iter: StoreIter<'a>,
+    // The key bytes of the last yielded entry.
+    cursor: Option<Vec<u8>>,
+    // The bytes of the first key that should not be yielded, if any.
+    end_bytes: Option<Vec<u8>>,
+}
+
+/// An iterator yielding key/value pairs from a table of type `T` in descending order.
+pub struct RevIter<'a, T> {
+    iter_bytes: RevIterBytes<'a>,
+    _table: PhantomData<T>,
+}
+
+/// An iterator yielding the byte representation of key/value pairs from a table of type `T` in
+/// descending order.
+///
+/// Each step performs a predecessor search via the `byte_search` module.
+pub struct RevIterBytes<'a> {
+    id_bytes: Arc<[u8]>,
+    tree: &'a Store,
+    // The key from which the next predecessor will be searched.
+    key_bytes: Option<Vec<u8>>,
+    // Whether or not the entry at `key_bytes` should be yielded.
+    inclusive: bool,
+}
+
+/// An iterator yielding only the keys from a table of type `T` in descending order.
+///
+/// Values are not deserialized.
+pub struct KeysRev<'a, T> {
+    iter_bytes: RevIterBytes<'a>,
+    _table: PhantomData<T>,
+}
+
+/// An iterator yielding only the values from a table of type `T`.
+///
+/// Keys are not deserialized.
+pub struct Values<'a, T> {
+    iter_bytes: IterBytes<'a>,
+    _table: PhantomData<T>,
+}
+
+/// An iterator yielding only the values from a table of type `T` whose keys precede some end key.
+///
+/// Keys are not deserialized.
+pub struct ValuesRange<'a, T> {
+    iter_bytes: IterBytes<'a>,
+    // The bytes of the first key that should not be yielded.
+    end_bytes: Vec<u8>,
+    _table: PhantomData<T>,
+}
+
+/// An iterator yielding key/value pairs from a table of type `T` in chunks of up to `chunk_size`.
+pub struct ChunkedIter<'a, T> {
+    iter: Iter<'a, T>,
+    chunk_size: usize,
+    // An error that ended the previous chunk early, yielded after that chunk.
+    err: Option<Error>,
+}
+
+/// An iterator yielding the keys of a table of type `T` along with their values mapped by `F`.
+pub struct MapValues<'a, T, F> {
+    iter: Iter<'a, T>,
+    f: F,
+}
+
+/// An iterator yielding key/value pairs from a table of type `T` until the predicate `F` first
+/// returns `false`.
+pub struct ScanWhile<'a, T, F> {
+    // `None` once the predicate has returned `false`.
+    iter: Option<Iter<'a, T>>,
+    pred: F,
+}
+
+/// An iterator that removes each entry from a table of type `T` as it is yielded.
+///
+/// Entries are removed only once they have been successfully read.
+pub struct Drain<'a, T> {
+    iter_bytes: IterBytes<'a>,
+    tree: &'a Store,
+    _table: PhantomData<T>,
+}
+
+/// A summary of a table's contents, as produced by `Reader::stats`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TableStats<K> {
+    /// The number of entries within the table.
+    pub count: usize,
+    /// The size of the table on disk in bytes, as returned by `Reader::size_bytes`.
+    pub size_bytes: usize,
+    /// The minimum key within the table, if any.
+    pub first_key: Option<K>,
+    /// The maximum key within the table, if any.
+    pub last_key: Option<K>,
+}
+
+/// A single difference between two tables of the same type, as produced by `Reader::diff`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Diff<K, V> {
+    /// An entry that exists only within the other table.
+    Added(K, V),
+    /// An entry that exists only within this table.
+    Removed(K, V),
+    /// An entry whose value differs between the tables, along with this and the other value.
+    Changed(K, V, V),
 }
 
 /// The possible errors that might occur while reading/writing a **Table** within a **sled::Tree**.
@@ -77,29 +309,158 @@ pub enum Error {
     Sled(sled::Error<()>),
     Bincode(bincode::Error),
     Bytekey(bytekey::Error),
+    /// Compressing or decompressing the bytes of a value failed.
+    Compression(io::Error),
+    /// A table's `ValueCodec` failed to encode or decode a value.
+    Codec(Box<StdError + Send + Sync>),
+    /// The checksum stored alongside a value does not match the checksum of its bytes.
+    ChecksumMismatch,
+    /// Every attempt made by `Writer::with_retries` failed due to contention, e.g. a
+    /// compare-and-swap that kept losing to other writers.
+    RetryExhausted,
+    /// A `step` of zero was given to a method that groups entries into windows of time.
+    ZeroStep,
+    /// A key yielded while iterating over a table could not be decoded.
+    DecodeKey {
+        /// The table `ID` bytes that prefix the key.
+        id_bytes: Vec<u8>,
+        /// The key bytes following the `ID` prefix.
+        key_bytes: Vec<u8>,
+        /// The error that occurred while decoding the key.
+        source: Box<Error>,
+    },
+    /// A value yielded while iterating over a table could not be decoded.
+    DecodeValue {
+        /// The table `ID` bytes that prefix the key.
+        id_bytes: Vec<u8>,
+        /// The key bytes following the `ID` prefix.
+        key_bytes: Vec<u8>,
+        /// The value bytes exactly as stored.
+        value_bytes: Vec<u8>,
+        /// The error that occurred while decoding the value.
+        source: Box<Error>,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The maximum number of compare-and-swap attempts made by methods like `Writer::swap` before
+/// returning `Error::RetryExhausted`.
+pub const DEFAULT_MAX_RETRIES: usize = 1_000;
+
 // Implementations
 
-impl<'a, T> Reader<'a, T>
+impl<'a, T, S> Reader<'a, T, S>
 where
     T: Table,
+    S: Store,
 {
+    // Create a reader for the table within the given tree, caching the serialized `T::ID`.
+    //
+    // Panics if `T::ID` cannot be serialized.
+    fn new(tree: &'a S) -> Self {
+        let id_bytes: Arc<[u8]> = bytekey::serialize(&T::ID)
+            .expect("failed to serialize `Table::ID`")
+            .into();
+        Reader {
+            tree,
+            #[cfg(all(debug_assertions, feature = "registry"))]
+            registration: {
+                let store = tree as *const S as usize;
+                registry::register(store, &id_bytes, std::any::type_name::<T>())
+            },
+            id_bytes,
+            _table: PhantomData,
+        }
+    }
+
+    // The serialized `T::ID` that prefixes every key within the table.
+    fn id_bytes(&self) -> &[u8] {
+        &self.id_bytes
+    }
+
+    // Serialize the given key, prefixed by the cached `T::ID` bytes.
+    fn key_bytes(&self, key: &T::Key) -> Result<Vec<u8>> {
+        let mut key_bytes = self.id_bytes().to_vec();
+        bytekey::serialize_into(&mut key_bytes, key)?;
+        Ok(key_bytes)
+    }
+
     /// Retrieve a value from the **Tree** if it exists.
     pub fn get(&self, key: &T::Key) -> Result<Option<T::Value>> {
-        let key_bytes = write_key::<T>(key)?;
+        let key_bytes = self.key_bytes(key)?;
         let maybe_value_bytes = self.tree.get(&key_bytes)?;
         match maybe_value_bytes {
             None => Ok(None),
             Some(value_bytes) => {
-                let value = bincode::deserialize(&value_bytes)?;
+                let value = read_value::<T>(&value_bytes)?;
                 Ok(Some(value))
             },
         }
     }
 
+    /// Retrieve a value from the **Tree**, or the default value if it does not exist.
+    pub fn get_or_default(&self, key: &T::Key) -> Result<T::Value>
+    where
+        T::Value: Default,
+    {
+        Ok(self.get(key)?.unwrap_or_default())
+    }
+
+    /// Retrieve a value from the **Tree** if it exists and satisfies the given predicate.
+    ///
+    /// Returns `None` if `pred` returns `false`, even if a value exists for the **key**.
+    pub fn get_if<F>(&self, key: &T::Key, pred: F) -> Result<Option<T::Value>>
+    where
+        F: FnOnce(&T::Value) -> bool,
+    {
+        Ok(self.get(key)?.and_then(|value| match pred(&value) {
+            true => Some(value),
+            false => None,
+        }))
+    }
+
+    /// Retrieve the raw bytes of a value from the **Tree** if it exists.
+    ///
+    /// The bytes are returned exactly as they are stored, i.e. as produced by `write_value`.
+    pub fn get_raw(&self, key: &T::Key) -> Result<Option<Vec<u8>>> {
+        let key_bytes = self.key_bytes(key)?;
+        let maybe_value_bytes = self.tree.get(&key_bytes)?;
+        Ok(maybe_value_bytes)
+    }
+
+    /// Read the schema version with which the value for the given **key** was written.
+    ///
+    /// Only the leading version byte is read, so the value is not deserialized. Returns `None` if
+    /// no value exists for the key or if the table is not versioned, i.e. `T::VERSION` is `None`.
+    pub fn stored_version(&self, key: &T::Key) -> Result<Option<u8>> {
+        if T::VERSION.is_none() {
+            return Ok(None);
+        }
+        match self.get_raw(key)? {
+            None => Ok(None),
+            Some(value_bytes) => match value_bytes.first() {
+                None => Err(unexpected_eof()),
+                Some(&version) => Ok(Some(version)),
+            },
+        }
+    }
+
+    /// Retrieve a value from the **Tree** along with the bytes of its stored key if it exists.
+    ///
+    /// The key bytes include the `T::ID` prefix, i.e. they are equal to `write_key::<T>(key)`.
+    pub fn get_entry(&self, key: &T::Key) -> Result<Option<(Vec<u8>, T::Value)>> {
+        let key_bytes = self.key_bytes(key)?;
+        let maybe_value_bytes = self.tree.get(&key_bytes)?;
+        match maybe_value_bytes {
+            None => Ok(None),
+            Some(value_bytes) => {
+                let value = read_value::<T>(&value_bytes)?;
+                Ok(Some((key_bytes, value)))
+            },
+        }
+    }
+
     /// Iterate over all key value pairs in the table.
     pub fn iter(&self) -> Result<Iter<'a, T>> {
         let iter_bytes = self.iter_bytes()?;
@@ -107,6 +468,46 @@ where
         Ok(Iter { iter_bytes, _table })
     }
 
+    /// Iterate over all key value pairs in the table, with the ability to `peek` at the next entry
+    /// without consuming it.
+    pub fn iter_peekable(&self) -> Result<PeekableIter<'a, T>> {
+        let iter = self.iter()?;
+        let peeked = None;
+        Ok(PeekableIter { iter, peeked })
+    }
+
+    /// A `futures::Stream` over all key value pairs in the table.
+    ///
+    /// The stream wraps the blocking `Iter`, reading each entry inline when polled. Errors that
+    /// occur while reading an entry end the stream.
+    #[cfg(feature = "async")]
+    pub fn stream(&self) -> Result<TableStream<'a, T>> {
+        let iter = self.iter()?;
+        Ok(TableStream { iter })
+    }
+
+    /// Iterate over all key value pairs in the table in chunks of up to `chunk_size` entries.
+    ///
+    /// Only the final chunk may contain fewer than `chunk_size` entries. If an error occurs while
+    /// reading a chunk, the entries read so far are yielded as a chunk followed by the error.
+    ///
+    /// Panics if `chunk_size` is `0`.
+    pub fn iter_chunked(&self, chunk_size: usize) -> Result<ChunkedIter<'a, T>> {
+        assert!(chunk_size != 0, "chunk_size must be greater than 0");
+        let iter = self.iter()?;
+        let err = None;
+        Ok(ChunkedIter { iter, chunk_size, err })
+    }
+
+    /// Iterate over all key value pairs in the table, mapping each value via the given function.
+    pub fn map_values<F, U>(&self, f: F) -> Result<MapValues<'a, T, F>>
+    where
+        F: FnMut(T::Value) -> U,
+    {
+        let iter = self.iter()?;
+        Ok(MapValues { iter, f })
+    }
+
     /// Iterate over tuples of keys and values, starting at the provided key.
     pub fn scan(&self, key: &T::Key) -> Result<Iter<'a, T>> {
         let iter_bytes = self.scan_bytes(key)?;
@@ -114,6 +515,40 @@ where
         Ok(Iter { iter_bytes, _table })
     }
 
+    /// Iterate over tuples of keys and values whose keys fall within `lo..hi`.
+    ///
+    /// The entry for `lo` is included if it exists while the entry for `hi` is not, mirroring the
+    /// timestamp module's `scan_range`. As `bytekey` preserves ordering, the end of the range is
+    /// found by comparing encoded keys.
+    pub fn scan_range(&self, lo: &T::Key, hi: &T::Key) -> Result<Iter<'a, T>> {
+        let mut iter_bytes = self.scan_bytes(lo)?;
+        iter_bytes.end_bytes = Some(self.key_bytes(hi)?);
+        let _table = PhantomData;
+        Ok(Iter { iter_bytes, _table })
+    }
+
+    /// Iterate over tuples of keys and values, starting at the provided key and stopping at the
+    /// first entry for which `pred` returns `false`.
+    ///
+    /// This allows for ending a scan on some computed condition rather than a fixed bound.
+    pub fn scan_while<F>(&self, start: &T::Key, pred: F) -> Result<ScanWhile<'a, T, F>>
+    where
+        F: FnMut(&T::Key, &T::Value) -> bool,
+    {
+        let iter = Some(self.scan(start)?);
+        Ok(ScanWhile { iter, pred })
+    }
+
+    /// The span of bytes occupied by the keys of all entries within the table.
+    ///
+    /// Returns the inclusive start of the span, i.e. the bytes of `T::ID`, along with the exclusive
+    /// end of the span, i.e. the first bytes that follow every key beginning with `T::ID`. The end
+    /// is `None` if no such bytes exist, e.g. when `T::ID` consists only of `0xFF` bytes.
+    pub fn key_range(&self) -> Result<(Vec<u8>, Option<Vec<u8>>)> {
+        let id_bytes = self.id_bytes();
+        Ok((id_bytes.to_vec(), prefix_end(id_bytes)))
+    }
+
     /// Iterate over the byte representation of all key/value pairs within the table.
     ///
     /// The yielded bytes for each entry are laid out as follows:
@@ -122,9 +557,11 @@ where
     /// ([T::ID, T::Key], [T::Value])
     /// ```
     pub fn iter_bytes(&self) -> Result<IterBytes<'a>> {
-        let id_bytes: Vec<u8> = bytekey::serialize(&T::ID)?;
+        let id_bytes = self.id_bytes.clone();
+        let prefix_bytes = vec![];
         let iter = self.tree.scan(&id_bytes);
-        Ok(IterBytes { id_bytes, iter })
+        let (cursor, end_bytes) = (None, None);
+        Ok(IterBytes { id_bytes, prefix_bytes, iter, cursor, end_bytes })
     }
 
     /// Iterate over the byte representation of all key/value pairs within the table.
@@ -135,10 +572,247 @@ where
     /// ([T::ID, T::Key], [T::Value])
     /// ```
     pub fn scan_bytes(&self, key: &T::Key) -> Result<IterBytes<'a>> {
-        let id_bytes = bytekey::serialize(&T::ID)?;
-        let key_bytes = write_key::<T>(key)?;
+        let id_bytes = self.id_bytes.clone();
+        let prefix_bytes = vec![];
+        let key_bytes = self.key_bytes(key)?;
+        let iter = self.tree.scan(&key_bytes);
+        let (cursor, end_bytes) = (None, None);
+        Ok(IterBytes { id_bytes, prefix_bytes, iter, cursor, end_bytes })
+    }
+
+    /// Iterate over tuples of keys and values, starting after the given `cursor`.
+    ///
+    /// The `cursor` should be the bytes produced by `Iter::cursor`, allowing iteration to be
+    /// resumed (e.g. after a restart) without reconstructing the last yielded `T::Key`.
+    pub fn iter_from_raw(&self, cursor: &[u8]) -> Result<Iter<'a, T>> {
+        let iter_bytes = self.iter_bytes_from_raw(cursor)?;
+        let _table = PhantomData;
+        Ok(Iter { iter_bytes, _table })
+    }
+
+    /// Iterate over the byte representation of all key/value pairs, starting after the given
+    /// `cursor`.
+    ///
+    /// The yielded bytes for each entry are laid out as follows:
+    ///
+    /// ```txt
+    /// ([T::ID, T::Key], [T::Value])
+    /// ```
+    pub fn iter_bytes_from_raw(&self, cursor: &[u8]) -> Result<IterBytes<'a>> {
+        let id_bytes = self.id_bytes.clone();
+        let prefix_bytes = vec![];
+        // The smallest key that follows the cursor.
+        let mut key_bytes = cursor.to_vec();
+        key_bytes.push(0);
         let iter = self.tree.scan(&key_bytes);
-        Ok(IterBytes { id_bytes, iter })
+        let (cursor, end_bytes) = (None, None);
+        Ok(IterBytes { id_bytes, prefix_bytes, iter, cursor, end_bytes })
+    }
+
+    /// Collect all entries whose keys fall within `start..end` into a map.
+    ///
+    /// As within `scan_range`, the end of the range is found by comparing encoded keys. Returns the
+    /// first error that occurs while iterating, if any.
+    pub fn collect_range(&self, start: &T::Key, end: &T::Key) -> Result<BTreeMap<T::Key, T::Value>>
+    where
+        T::Key: Ord,
+    {
+        self.scan_range(start, end)?.collect()
+    }
+
+    /// Whether or not any entry exists whose key falls within `start..end`.
+    ///
+    /// Only the first entry following `start` is read, and as `bytekey` preserves ordering its key
+    /// is compared with `end` in its encoded form, so neither keys nor values are deserialized.
+    pub fn any_in_range(&self, start: &T::Key, end: &T::Key) -> Result<bool> {
+        let end_bytes = self.key_bytes(end)?;
+        match self.scan_bytes(start)?.next() {
+            None => Ok(false),
+            Some(Err(err)) => Err(err),
+            Some(Ok((key_bytes, _))) => Ok(key_bytes < end_bytes),
+        }
+    }
+
+    /// Whether or not any key within the table maps to the given **value**.
+    ///
+    /// As tables have no index of their values, this scans the table and compares each decoded
+    /// value in turn, returning as soon as a match is found. This is `O(n)` in the number of
+    /// entries, so **Reversible** tables should look up the value via their reverse table instead.
+    /// Keys are not deserialized.
+    pub fn contains_value(&self, value: &T::Value) -> Result<bool>
+    where
+        T::Value: PartialEq,
+    {
+        for res in self.iter_bytes()? {
+            let (_, value_bytes) = res?;
+            if read_value::<T>(&value_bytes)? == *value {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Iterate over values, starting at the provided key.
+    ///
+    /// Keys are not deserialized, making this cheaper than `scan` when only values are required.
+    pub fn scan_values(&self, key: &T::Key) -> Result<Values<'a, T>> {
+        let iter_bytes = self.scan_bytes(key)?;
+        let _table = PhantomData;
+        Ok(Values { iter_bytes, _table })
+    }
+
+    /// Iterate over the values of all entries whose keys fall within `start..end`.
+    ///
+    /// As `bytekey` preserves ordering, the end of the range is found by comparing encoded keys, so
+    /// keys are not deserialized.
+    pub fn values_range(&self, start: &T::Key, end: &T::Key) -> Result<ValuesRange<'a, T>> {
+        let iter_bytes = self.scan_bytes(start)?;
+        let end_bytes = self.key_bytes(end)?;
+        let _table = PhantomData;
+        Ok(ValuesRange { iter_bytes, end_bytes, _table })
+    }
+
+    /// Iterate over tuples of keys and values whose keys begin with the given prefix.
+    ///
+    /// The `prefix` should be some leading portion of a `T::Key`, e.g. the first field of a tuple
+    /// or struct. As `bytekey` preserves ordering, all keys beginning with the prefix are stored
+    /// consecutively.
+    ///
+    /// **Note:** Variable-length fields (e.g. `String`s) are serialized with a terminating byte, so
+    /// a prefix ending with such a field only matches keys in which that field is equal.
+    pub fn scan_prefix<P>(&self, prefix: &P) -> Result<Iter<'a, T>>
+    where
+        P: Serialize,
+    {
+        let iter_bytes = self.scan_prefix_bytes(prefix)?;
+        let _table = PhantomData;
+        Ok(Iter { iter_bytes, _table })
+    }
+
+    /// Iterate over the byte representation of all key/value pairs whose keys begin with the
+    /// given prefix.
+    ///
+    /// The yielded bytes for each entry are laid out as follows:
+    ///
+    /// ```txt
+    /// ([T::ID, T::Key], [T::Value])
+    /// ```
+    pub fn scan_prefix_bytes<P>(&self, prefix: &P) -> Result<IterBytes<'a>>
+    where
+        P: Serialize,
+    {
+        let id_bytes = self.id_bytes.clone();
+        let prefix_bytes = bytekey::serialize(prefix)?;
+        let mut start_bytes = id_bytes.to_vec();
+        start_bytes.extend(&prefix_bytes);
+        let iter = self.tree.scan(&start_bytes);
+        let (cursor, end_bytes) = (None, None);
+        Ok(IterBytes { id_bytes, prefix_bytes, iter, cursor, end_bytes })
+    }
+
+    /// Iterate over tuples of keys and values, starting at the given already-serialized key.
+    ///
+    /// The `key_bytes` should be a `T::Key` as serialized by `bytekey`, excluding the `T::ID`
+    /// bytes, which are prepended before scanning. This produces the same entries as `scan` without
+    /// decoding and re-encoding keys received in their serialized form.
+    pub fn scan_raw(&self, key_bytes: &[u8]) -> Result<Iter<'a, T>> {
+        let id_bytes = self.id_bytes.clone();
+        let prefix_bytes = vec![];
+        let mut start_bytes = id_bytes.to_vec();
+        start_bytes.extend(key_bytes);
+        let iter = self.tree.scan(&start_bytes);
+        let (cursor, end_bytes) = (None, None);
+        let iter_bytes = IterBytes { id_bytes, prefix_bytes, iter, cursor, end_bytes };
+        let _table = PhantomData;
+        Ok(Iter { iter_bytes, _table })
+    }
+
+    /// Iterate over tuples of keys and values whose serialized keys begin with the given bytes.
+    ///
+    /// Unlike `scan_prefix`, the `prefix` is not serialized, allowing for iteration over keys
+    /// whose layout is irregular. The `prefix` excludes the `T::ID` bytes.
+    pub fn raw_prefix_iter(&self, prefix: &[u8]) -> Result<Iter<'a, T>> {
+        let id_bytes = self.id_bytes.clone();
+        let prefix_bytes = prefix.to_vec();
+        let mut start_bytes = id_bytes.to_vec();
+        start_bytes.extend(prefix);
+        let iter = self.tree.scan(&start_bytes);
+        let (cursor, end_bytes) = (None, None);
+        let iter_bytes = IterBytes { id_bytes, prefix_bytes, iter, cursor, end_bytes };
+        let _table = PhantomData;
+        Ok(Iter { iter_bytes, _table })
+    }
+
+    /// Count the entries whose keys begin with the given prefix.
+    ///
+    /// Values are not deserialized.
+    pub fn count_prefix<P>(&self, prefix: &P) -> Result<usize>
+    where
+        P: Serialize,
+    {
+        let mut count = 0;
+        for res in self.scan_prefix_bytes(prefix)? {
+            res?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Iterate over all key value pairs in the table in descending order.
+    pub fn iter_rev(&self) -> Result<RevIter<'a, T>> {
+        let iter_bytes = self.iter_rev_bytes()?;
+        let _table = PhantomData;
+        Ok(RevIter { iter_bytes, _table })
+    }
+
+    /// Iterate over the byte representation of all key/value pairs in descending order.
+    ///
+    /// The yielded bytes for each entry are laid out as follows:
+    ///
+    /// ```txt
+    /// ([T::ID, T::Key], [T::Value])
+    /// ```
+    pub fn iter_rev_bytes(&self) -> Result<RevIterBytes<'a>> {
+        let id_bytes = self.id_bytes.clone();
+        let last = byte_search::find_last(self.tree, &id_bytes)?;
+        let key_bytes = last.map(|(k, _)| k);
+        let tree: &'a Store = self.tree;
+        let inclusive = true;
+        Ok(RevIterBytes { id_bytes, tree, key_bytes, inclusive })
+    }
+
+    /// Iterate over tuples of keys and values in descending order, starting at the provided key.
+    ///
+    /// The entry for the given key is included if it exists.
+    pub fn scan_rev(&self, key: &T::Key) -> Result<RevIter<'a, T>> {
+        let iter_bytes = self.scan_rev_bytes(key)?;
+        let _table = PhantomData;
+        Ok(RevIter { iter_bytes, _table })
+    }
+
+    /// Iterate over keys in descending order, starting at the provided key.
+    ///
+    /// The given key is included if an entry exists for it. Values are not deserialized.
+    pub fn scan_keys_rev(&self, key: &T::Key) -> Result<KeysRev<'a, T>> {
+        let iter_bytes = self.scan_rev_bytes(key)?;
+        let _table = PhantomData;
+        Ok(KeysRev { iter_bytes, _table })
+    }
+
+    /// Iterate over the byte representation of key/value pairs in descending order, starting at
+    /// the provided key.
+    ///
+    /// The yielded bytes for each entry are laid out as follows:
+    ///
+    /// ```txt
+    /// ([T::ID, T::Key], [T::Value])
+    /// ```
+    pub fn scan_rev_bytes(&self, key: &T::Key) -> Result<RevIterBytes<'a>> {
+        let id_bytes = self.id_bytes.clone();
+        let key_bytes = Some(self.key_bytes(key)?);
+        let tree: &'a Store = self.tree;
+        let inclusive = true;
+        Ok(RevIterBytes { id_bytes, tree, key_bytes, inclusive })
     }
 
     /// Return the minimum entry within the table.
@@ -152,6 +826,42 @@ where
         }
     }
 
+    /// Return the minimum key within the table.
+    ///
+    /// This is similar to `min`, however the value is not deserialized.
+    pub fn min_key(&self) -> Result<Option<T::Key>> {
+        let mut iter_bytes = self.iter_bytes()?;
+        let id_len = iter_bytes.id_bytes.len();
+        match iter_bytes.next() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok((k, _))) => Ok(Some(bytekey::deserialize(&k[id_len..])?)),
+        }
+    }
+
+    /// Return the `n`th entry within the table, ordered by key.
+    ///
+    /// Returns `None` if the table contains `n` or fewer entries.
+    ///
+    /// This is similar to using the `iter().nth(n)` method, however only the returned entry is
+    /// deserialized.
+    pub fn nth(&self, n: usize) -> Result<Option<(T::Key, T::Value)>> {
+        let mut iter_bytes = self.iter_bytes()?;
+        for _ in 0..n {
+            match iter_bytes.next() {
+                None => return Ok(None),
+                Some(Err(err)) => return Err(err),
+                Some(Ok(_)) => (),
+            }
+        }
+        let _table = PhantomData;
+        match (Iter::<T> { iter_bytes, _table }).next() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(kv)) => Ok(Some(kv)),
+        }
+    }
+
     /// Return the entry that is the successor of the given key.
     ///
     /// Returns `None` if no such key exists.
@@ -193,16 +903,231 @@ where
         let mut bytes = 0;
         for res in self.iter_bytes()? {
             let (k, v) = res?;
-            bytes += k.len() + v.len();
+            bytes += entry_size_bytes(&k, &v);
         }
         Ok(bytes)
     }
-}
 
-impl<'a, T> Reader<'a, T>
-where
-    T: Table,
+    /// An approximation of the number of entries within the table.
+    ///
+    /// This divides `size_bytes` by the average size of the first few entries, so it is only
+    /// accurate for tables whose entries are of a similar size. Tables with fewer entries than the
+    /// sample are counted exactly.
+    ///
+    /// **Note:** `size_bytes` currently iterates over the whole table as sled does not yet expose
+    /// the size of a key range, so this is not yet any cheaper than an exact count.
+    pub fn estimate_len(&self) -> Result<usize> {
+        const SAMPLE_LEN: usize = 16;
+        let (mut sample_len, mut sample_bytes) = (0, 0);
+        for res in self.iter_bytes()?.take(SAMPLE_LEN) {
+            let (k, v) = res?;
+            sample_len += 1;
+            sample_bytes += entry_size_bytes(&k, &v);
+        }
+        if sample_len < SAMPLE_LEN {
+            return Ok(sample_len);
+        }
+        let size_bytes = self.size_bytes()?;
+        Ok(size_bytes * sample_len / sample_bytes)
+    }
+
+    /// The size on disk in bytes of the entry for the given **key**, or `None` if no entry exists.
+    ///
+    /// This includes the table ID prefix, the key and the value as counted by `size_bytes`. The
+    /// value is not deserialized.
+    pub fn entry_size(&self, key: &T::Key) -> Result<Option<usize>> {
+        let key_bytes = self.key_bytes(key)?;
+        let maybe_value_bytes = self.tree.get(&key_bytes)?;
+        Ok(maybe_value_bytes.map(|v| entry_size_bytes(&key_bytes, &v)))
+    }
+
+    /// Summarise the table's number of entries, size in bytes and first and last keys.
+    ///
+    /// All of these are gathered within a single pass over the table. Only the first and last keys
+    /// are deserialized and values are not deserialized at all.
+    pub fn stats(&self) -> Result<TableStats<T::Key>> {
+        let iter_bytes = self.iter_bytes()?;
+        let id_len = iter_bytes.id_bytes.len();
+        let mut stats = TableStats {
+            count: 0,
+            size_bytes: 0,
+            first_key: None,
+            last_key: None,
+        };
+        let mut last_key_bytes = None;
+        for res in iter_bytes {
+            let (k, v) = res?;
+            if stats.count == 0 {
+                stats.first_key = Some(bytekey::deserialize(&k[id_len..])?);
+            }
+            stats.count += 1;
+            stats.size_bytes += entry_size_bytes(&k, &v);
+            last_key_bytes = Some(k);
+        }
+        if let Some(k) = last_key_bytes {
+            stats.last_key = Some(bytekey::deserialize(&k[id_len..])?);
+        }
+        Ok(stats)
+    }
+
+    /// The size of the table's values on disk in bytes, excluding keys.
+    ///
+    /// Values are not deserialized.
+    pub fn value_bytes(&self) -> Result<usize> {
+        let mut bytes = 0;
+        for res in self.iter_bytes()? {
+            let (_, v) = res?;
+            bytes += v.len();
+        }
+        Ok(bytes)
+    }
+
+    /// Copy every entry within the table into the same table within the destination writer's
+    /// store, which may be a different tree.
+    ///
+    /// Returns the number of entries copied. As both tables are of type `T`, entries are copied as
+    /// raw bytes without being decoded and re-encoded.
+    pub fn copy_to<'b, D>(&self, dst: &Writer<'b, T, D>) -> Result<usize>
+    where
+        D: Store,
+    {
+        let mut count = 0;
+        for res in self.iter_bytes()? {
+            let (key_bytes, value_bytes) = res?;
+            dst.tree.set(key_bytes, value_bytes)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Compare the table with the same table within another store, producing the differences
+    /// required to turn this table into the `other`.
+    ///
+    /// Both tables are walked in key order at once, so each entry is read only once. Values are
+    /// only deserialized for entries that are added, removed or whose bytes differ.
+    pub fn diff<'b, D>(&self, other: &Reader<'b, T, D>) -> Result<Vec<Diff<T::Key, T::Value>>>
+    where
+        T::Value: PartialEq,
+        D: Store,
+    {
+        let (mut iter, mut other_iter) = (self.iter_bytes()?, other.iter_bytes()?);
+        let id_len = iter.id_bytes.len();
+        let (mut entry, mut other_entry) = (next_bytes(&mut iter)?, next_bytes(&mut other_iter)?);
+        let mut diffs = vec![];
+        loop {
+            let ord = match (&entry, &other_entry) {
+                (&None, &None) => break,
+                (&Some(_), &None) => cmp::Ordering::Less,
+                (&None, &Some(_)) => cmp::Ordering::Greater,
+                (&Some((ref k, _)), &Some((ref other_k, _))) => k.cmp(other_k),
+            };
+            match ord {
+                cmp::Ordering::Less => {
+                    let (k, v) = entry.take().expect("no entry");
+                    let (k, v) = read_entry::<T>(&k[id_len..], &v)?;
+                    diffs.push(Diff::Removed(k, v));
+                    entry = next_bytes(&mut iter)?;
+                }
+                cmp::Ordering::Greater => {
+                    let (k, v) = other_entry.take().expect("no entry");
+                    let (k, v) = read_entry::<T>(&k[id_len..], &v)?;
+                    diffs.push(Diff::Added(k, v));
+                    other_entry = next_bytes(&mut other_iter)?;
+                }
+                cmp::Ordering::Equal => {
+                    let (k, v) = entry.take().expect("no entry");
+                    let (_, other_v) = other_entry.take().expect("no entry");
+                    if v != other_v {
+                        let (k, v) = read_entry::<T>(&k[id_len..], &v)?;
+                        let other_v = read_value::<T>(&other_v)?;
+                        if v != other_v {
+                            diffs.push(Diff::Changed(k, v, other_v));
+                        }
+                    }
+                    entry = next_bytes(&mut iter)?;
+                    other_entry = next_bytes(&mut other_iter)?;
+                }
+            }
+        }
+        Ok(diffs)
+    }
+
+    /// Fold the raw bytes of every entry within the table into a single value.
+    ///
+    /// Each entry is given as its key bytes (including the `T::ID` prefix) along with its value
+    /// bytes exactly as stored. Neither keys nor values are deserialized.
+    pub fn fold_raw<B, F>(&self, init: B, mut f: F) -> Result<B>
+    where
+        F: FnMut(B, &[u8], &[u8]) -> B,
+    {
+        let mut acc = init;
+        for res in self.iter_bytes()? {
+            let (k, v) = res?;
+            acc = f(acc, &k, &v);
+        }
+        Ok(acc)
+    }
+
+    /// Fold every entry within the table into a single value without collecting them.
+    ///
+    /// Returns the first error that occurs while iterating, if any.
+    pub fn try_fold<B, F>(&self, init: B, mut f: F) -> Result<B>
+    where
+        F: FnMut(B, (T::Key, T::Value)) -> B,
+    {
+        let mut acc = init;
+        for res in self.iter()? {
+            acc = f(acc, res?);
+        }
+        Ok(acc)
+    }
+
+    /// Call `f` with every entry within the table in order, stopping at the first error.
+    ///
+    /// Returns the first error produced by either iteration or `f`, in which case no further
+    /// entries are read.
+    pub fn try_for_each<F>(&self, mut f: F) -> Result<()>
+    where
+        F: FnMut(T::Key, T::Value) -> Result<()>,
+    {
+        for res in self.iter()? {
+            let (k, v) = res?;
+            f(k, v)?;
+        }
+        Ok(())
+    }
+
+    /// Select a pseudo-random entry from the table, or `None` if the table is empty.
+    ///
+    /// Every entry is equally likely to be selected, however this visits every entry within the
+    /// table via reservoir sampling. Tables with an **UnsignedBinarySearchKey** may use the much
+    /// cheaper `sample` method instead.
+    #[cfg(feature = "rand")]
+    pub fn sample_scan<R>(&self, rng: &mut R) -> Result<Option<(T::Key, T::Value)>>
+    where
+        R: rand::Rng,
+    {
+        let iter_bytes = self.iter_bytes()?;
+        let id_len = iter_bytes.id_bytes.len();
+        let mut selected = None;
+        for (i, res) in iter_bytes.enumerate() {
+            let entry = res?;
+            if rng.gen_range(0, i + 1) == 0 {
+                selected = Some(entry);
+            }
+        }
+        match selected {
+            None => Ok(None),
+            Some((k, v)) => read_entry::<T>(&k[id_len..], &v).map(Some),
+        }
+    }
+}
+
+impl<'a, T, S> Reader<'a, T, S>
+where
+    T: Table,
     T::Key: UnsignedBinarySearchKey,
+    S: Store,
 {
     /// Find and return the maximum entry within the table.
     ///
@@ -211,6 +1136,22 @@ where
         unsigned_binary_search::find_max(self)
     }
 
+    /// Find and return the maximum key within the table.
+    ///
+    /// This performs the same search as `max`, however no values are deserialized.
+    pub fn max_key(&self) -> Result<Option<T::Key>> {
+        unsigned_binary_search::find_max_key(self)
+    }
+
+    /// Find and return the raw key and value bytes of the maximum entry within the table.
+    ///
+    /// This performs the same search as `max`, however neither the key nor value is deserialized.
+    /// The key bytes include the table's `ID` prefix, as yielded by `iter_bytes`, and so may be
+    /// persisted as an opaque cursor to resume from via `iter_bytes_from_raw`.
+    pub fn max_raw(&self) -> Result<Option<(Vec<u8>, Vec<u8>)>> {
+        unsigned_binary_search::find_max_raw(self)
+    }
+
     /// Find and return the entry that is equal to or precedes the given key.
     ///
     /// Returns `None` if no such key exists.
@@ -224,32 +1165,444 @@ where
     pub fn pred(&self, key: &T::Key) -> Result<Option<(T::Key, T::Value)>> {
         unsigned_binary_search::find_pred(self, key, false)
     }
+
+    /// Find and return the greatest entry whose key is ordered before or equal to some target
+    /// according to the given comparator.
+    ///
+    /// The comparator should return `Less` or `Equal` for all keys up to the target and `Greater`
+    /// for all keys after it. This allows for locating the boundary of any predicate that is
+    /// monotonic over the key space.
+    ///
+    /// Returns `None` if no such key exists.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<Option<(T::Key, T::Value)>>
+    where
+        F: FnMut(&T::Key) -> cmp::Ordering,
+    {
+        unsigned_binary_search::find_pred_by(self, f)
+    }
+
+    /// Select a pseudo-random entry from the table, or `None` if the table is empty.
+    ///
+    /// A random key is chosen from the full range of the key's unsigned integer representation
+    /// and the first entry at or following it is returned, wrapping around to the minimum entry.
+    ///
+    /// **Note:** each entry is selected with a probability proportional to the gap between its
+    /// key and the preceding key, so the distribution is only uniform for tables whose keys are
+    /// evenly spread over the key space. Use `sample_scan` where a uniform distribution is
+    /// required.
+    #[cfg(feature = "rand")]
+    pub fn sample<R>(&self, rng: &mut R) -> Result<Option<(T::Key, T::Value)>>
+    where
+        R: rand::Rng,
+        rand::distributions::Standard:
+            rand::distributions::Distribution<<T::Key as UnsignedBinarySearchKey>::UnsignedInteger>,
+    {
+        let key = T::Key::from_unsigned_integer(rng.gen());
+        match self.succ_incl(&key)? {
+            None => self.min(),
+            Some(entry) => Ok(Some(entry)),
+        }
+    }
 }
 
-impl<'a, T> Writer<'a, T>
+impl<'a, T, S> Writer<'a, T, S>
 where
     T: Table,
+    S: Store,
 {
     /// Set the given **key** to a new **value**.
     pub fn set(&self, key: &T::Key, value: &T::Value) -> Result<()> {
-        let key_bytes = write_key::<T>(key)?;
-        let value_bytes = bincode::serialize(value)?;
+        let key_bytes = self.key_bytes(key)?;
+        let value_bytes = write_value::<T>(value)?;
         self.tree.set(key_bytes, value_bytes)?;
         Ok(())
     }
 
+    /// Set the key of the given **entry** to its value.
+    ///
+    /// This is a convenience for setting the `(key, value)` tuples yielded by an iterator.
+    pub fn set_entry(&self, entry: &(T::Key, T::Value)) -> Result<()> {
+        let (ref key, ref value) = *entry;
+        self.set(key, value)
+    }
+
+    /// Set the given owned **key** to a new owned **value**.
+    pub fn set_owned(&self, key: T::Key, value: T::Value) -> Result<()> {
+        self.set(&key, &value)
+    }
+
+    /// Merge the given `operand` into the value for the given **key** via `T::merge`.
+    ///
+    /// The merge is applied by the tree itself without a separate read, so `merge_operator::<T>`
+    /// must have been registered with the tree's config.
+    pub fn merge(&self, key: &T::Key, operand: &T::Value) -> Result<()> {
+        let key_bytes = self.key_bytes(key)?;
+        let operand_bytes = write_value::<T>(operand)?;
+        self.tree.merge(key_bytes, operand_bytes)?;
+        Ok(())
+    }
+
     /// Remove a value from the **Tree** if it exists.
     pub fn del(&self, key: &T::Key) -> Result<Option<T::Value>> {
-        let key_bytes = write_key::<T>(key)?;
+        let key_bytes = self.key_bytes(key)?;
         let maybe_value_bytes = self.tree.del(&key_bytes)?;
         match maybe_value_bytes {
             None => Ok(None),
             Some(value_bytes) => {
-                let value = bincode::deserialize(&value_bytes)?;
+                let value = read_value::<T>(&value_bytes)?;
                 Ok(Some(value))
             },
         }
     }
+
+    /// Set the given **key** to the given raw value bytes.
+    ///
+    /// The bytes are stored as-is, so they must be a valid encoding of a `T::Value` as produced by
+    /// `write_value` in order for the entry to remain readable by the typed API.
+    pub fn set_raw(&self, key: &T::Key, value_bytes: &[u8]) -> Result<()> {
+        let key_bytes = self.key_bytes(key)?;
+        self.tree.set(key_bytes, value_bytes.to_vec())?;
+        Ok(())
+    }
+
+    /// Set the raw value bytes for the given **key** to `new` only if they are currently `old`,
+    /// where `None` represents an absent entry.
+    ///
+    /// The bytes are compared and stored as-is, so as within `set_raw` they must be a valid
+    /// encoding of a `T::Value` for the entry to remain readable by the typed API. If the current
+    /// bytes do not match `old`, they are returned within the inner `Err`. As with `Store::cas`,
+    /// these are `None` if no entry exists, as an empty value is a valid encoding for some types.
+    pub fn cas_raw(
+        &self,
+        key: &T::Key,
+        old: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), Option<Vec<u8>>>> {
+        let key_bytes = self.key_bytes(key)?;
+        let old = old.map(|bytes| bytes.to_vec());
+        let new = new.map(|bytes| bytes.to_vec());
+        self.tree.cas(key_bytes, old, new)
+    }
+
+    /// Atomically set the given **key** to a new **value**, returning the previous value.
+    ///
+    /// This is performed via a compare-and-swap loop, ensuring that the returned value is exactly
+    /// the one that was replaced even when other writers are setting the same key. Returns
+    /// `Error::RetryExhausted` if the swap fails `DEFAULT_MAX_RETRIES` times due to contention.
+    pub fn swap(&self, key: &T::Key, value: &T::Value) -> Result<Option<T::Value>> {
+        let key_bytes = self.key_bytes(key)?;
+        let value_bytes = write_value::<T>(value)?;
+        match self.swap_bytes(key_bytes, value_bytes)? {
+            None => Ok(None),
+            Some(old_bytes) => Ok(Some(read_value::<T>(&old_bytes)?)),
+        }
+    }
+
+    /// Set each of the given entries, returning the keys that did not previously exist.
+    ///
+    /// Each entry is set atomically as within `swap`, however previous values are not
+    /// deserialized.
+    pub fn upsert_all<I>(&self, entries: I) -> Result<Vec<T::Key>>
+    where
+        I: IntoIterator<Item = (T::Key, T::Value)>,
+    {
+        let mut created = vec![];
+        for (key, value) in entries {
+            let key_bytes = self.key_bytes(&key)?;
+            let value_bytes = write_value::<T>(&value)?;
+            if self.swap_bytes(key_bytes, value_bytes)?.is_none() {
+                created.push(key);
+            }
+        }
+        Ok(created)
+    }
+
+    /// Atomically merge the given **value** with the existing value for **key**, inserting it as is
+    /// if no value exists, and return the resulting value.
+    ///
+    /// `merge` is called with the existing value followed by the new **value**. This is performed
+    /// via a compare-and-swap loop, so `merge` may be called more than once if other writers are
+    /// modifying the same key. Returns `Error::RetryExhausted` if the merge fails
+    /// `DEFAULT_MAX_RETRIES` times due to contention.
+    pub fn insert_or_merge<F>(
+        &self,
+        key: &T::Key,
+        value: T::Value,
+        mut merge: F,
+    ) -> Result<T::Value>
+    where
+        T::Value: Clone,
+        F: FnMut(T::Value, T::Value) -> T::Value,
+    {
+        let key_bytes = self.key_bytes(key)?;
+        let mut old_bytes = self.tree.get(&key_bytes)?;
+        self.with_retries(DEFAULT_MAX_RETRIES, || {
+            let merged = match old_bytes {
+                None => value.clone(),
+                Some(ref bytes) => merge(read_value::<T>(bytes)?, value.clone()),
+            };
+            let new_bytes = Some(write_value::<T>(&merged)?);
+            match self.tree.cas(key_bytes.clone(), old_bytes.clone(), new_bytes)? {
+                Ok(()) => Ok(Some(merged)),
+                Err(current_bytes) => {
+                    old_bytes = current_bytes;
+                    Ok(None)
+                }
+            }
+        })
+    }
+
+    /// Atomically replace the value for the given **key** with the result of `f`, returning both
+    /// the previous and the resulting value as `(old, new)`.
+    ///
+    /// `f` is called with the existing value if there is one. If `f` returns `None`, the entry is
+    /// removed. This is performed via a compare-and-swap loop, so `f` may be called more than once
+    /// if other writers are modifying the same key. Returns `Error::RetryExhausted` if the
+    /// modification fails `DEFAULT_MAX_RETRIES` times due to contention.
+    pub fn get_and_modify<F>(
+        &self,
+        key: &T::Key,
+        mut f: F,
+    ) -> Result<(Option<T::Value>, Option<T::Value>)>
+    where
+        F: FnMut(Option<&T::Value>) -> Option<T::Value>,
+    {
+        let key_bytes = self.key_bytes(key)?;
+        let mut old_bytes = self.tree.get(&key_bytes)?;
+        self.with_retries(DEFAULT_MAX_RETRIES, || {
+            let old = match old_bytes {
+                None => None,
+                Some(ref bytes) => Some(read_value::<T>(bytes)?),
+            };
+            let new = f(old.as_ref());
+            let new_bytes = match new {
+                None => None,
+                Some(ref value) => Some(write_value::<T>(value)?),
+            };
+            match self.tree.cas(key_bytes.clone(), old_bytes.clone(), new_bytes)? {
+                Ok(()) => Ok(Some((old, new))),
+                Err(current_bytes) => {
+                    old_bytes = current_bytes;
+                    Ok(None)
+                }
+            }
+        })
+    }
+
+    // Atomically set the given key bytes to the new value bytes, returning the previous bytes.
+    fn swap_bytes(&self, key_bytes: Vec<u8>, value_bytes: Vec<u8>) -> Result<Option<Vec<u8>>> {
+        let mut old_bytes = self.tree.get(&key_bytes)?;
+        self.with_retries(DEFAULT_MAX_RETRIES, || {
+            let new_bytes = Some(value_bytes.clone());
+            match self.tree.cas(key_bytes.clone(), old_bytes.clone(), new_bytes)? {
+                Ok(()) => Ok(Some(old_bytes.take())),
+                Err(current_bytes) => {
+                    old_bytes = current_bytes;
+                    Ok(None)
+                }
+            }
+        })
+    }
+
+    /// Remove the entry for the given **key** from the **Tree** if it exists.
+    ///
+    /// Returns whether or not an entry was removed. Unlike `del`, the removed value is not
+    /// deserialized.
+    pub fn remove(&self, key: &T::Key) -> Result<bool> {
+        let key_bytes = self.key_bytes(key)?;
+        let maybe_value_bytes = self.tree.del(&key_bytes)?;
+        Ok(maybe_value_bytes.is_some())
+    }
+
+    /// Remove the entries for all of the given **keys** from the **Tree**.
+    ///
+    /// Returns the number of keys for which an entry existed. Values are not deserialized.
+    ///
+    /// All keys are serialized before any entry is removed, so a key that fails to serialize leaves
+    /// the table unchanged.
+    ///
+    /// **Note:** The pinned version of sled does not yet support batched writes, so entries are
+    /// removed one at a time in the given order and other readers may observe a partially applied
+    /// removal. If removing an entry fails, the entries for all preceding keys have been removed
+    /// and those for the following keys remain.
+    pub fn del_batch<I>(&self, keys: I) -> Result<usize>
+    where
+        I: IntoIterator<Item = T::Key>,
+    {
+        let mut keys_bytes = vec![];
+        for key in keys {
+            keys_bytes.push(self.key_bytes(&key)?);
+        }
+        let mut count = 0;
+        for key_bytes in keys_bytes {
+            if self.tree.del(&key_bytes)?.is_some() {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Remove all entries whose keys fall within `start..end`.
+    ///
+    /// Returns the number of entries removed. As within `values_range`, the end of the range is
+    /// found by comparing encoded keys, so neither keys nor values are deserialized. Entries of
+    /// other tables within the tree are never removed.
+    ///
+    /// **Note:** The pinned version of sled does not yet support batched writes, so entries are
+    /// removed one at a time in ascending order of key and other readers may observe a partially
+    /// applied removal. If removing an entry fails, all entries preceding it within the range have
+    /// been removed and those following it remain.
+    pub fn clear_range(&self, start: &T::Key, end: &T::Key) -> Result<usize> {
+        let end_bytes = self.key_bytes(end)?;
+        let mut keys_bytes = vec![];
+        for res in self.scan_bytes(start)? {
+            let (key_bytes, _) = res?;
+            if key_bytes >= end_bytes {
+                break;
+            }
+            keys_bytes.push(key_bytes);
+        }
+        let mut count = 0;
+        for key_bytes in keys_bytes {
+            if self.tree.del(&key_bytes)?.is_some() {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Replace the entire contents of the table with the given entries.
+    ///
+    /// All entries are serialized before the table is modified, so an entry that fails to
+    /// serialize leaves the table unchanged. Existing entries whose keys are absent from
+    /// `entries` are removed before the new entries are set. If a key occurs more than once, the
+    /// last of its values is kept.
+    ///
+    /// **Note:** The pinned version of sled does not yet support batched writes, so entries are
+    /// written one at a time and other readers may observe a partially replaced table. If a write
+    /// fails, the table holds some of its old entries alongside some of the new ones, so callers
+    /// should retry the replacement until it succeeds.
+    pub fn replace_all<I>(&self, entries: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (T::Key, T::Value)>,
+    {
+        let mut new_entries = BTreeMap::new();
+        for (key, value) in entries {
+            new_entries.insert(self.key_bytes(&key)?, write_value::<T>(&value)?);
+        }
+        let mut stale_keys = vec![];
+        for res in self.iter_bytes()? {
+            let (key_bytes, _) = res?;
+            if !new_entries.contains_key(&key_bytes) {
+                stale_keys.push(key_bytes);
+            }
+        }
+        for key_bytes in stale_keys {
+            self.tree.del(&key_bytes)?;
+        }
+        for (key_bytes, value_bytes) in new_entries {
+            self.tree.set(key_bytes, value_bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Set the given **key** to the **value** only if the key does not already exist.
+    ///
+    /// Returns whether or not the value was set. This is performed atomically via
+    /// compare-and-swap, so at most one of many concurrent callers may set the same key.
+    pub fn set_if_absent(&self, key: &T::Key, value: &T::Value) -> Result<bool> {
+        let key_bytes = self.key_bytes(key)?;
+        let value_bytes = write_value::<T>(value)?;
+        let set = self.tree.cas(key_bytes, None, Some(value_bytes))?.is_ok();
+        Ok(set)
+    }
+
+    /// Move the value for the `from` key to the `to` key, replacing any existing value for `to`.
+    ///
+    /// Returns whether or not an entry existed for `from`.
+    ///
+    /// **Note:** sled does not yet support transactions, so the move is performed as a pair of
+    /// compare-and-swaps. The value is written to `to` before `from` is removed, so a crash in
+    /// between may leave the value under both keys but never under neither. If `from` is changed
+    /// by another writer in between, `to` is restored and the move is retried.
+    pub fn move_key(&self, from: &T::Key, to: &T::Key) -> Result<bool> {
+        let from_bytes = self.key_bytes(from)?;
+        let to_bytes = self.key_bytes(to)?;
+        if from_bytes == to_bytes {
+            return Ok(self.tree.get(&from_bytes)?.is_some());
+        }
+        self.with_retries(DEFAULT_MAX_RETRIES, || {
+            let value_bytes = match self.tree.get(&from_bytes)? {
+                None => return Ok(Some(false)),
+                Some(value_bytes) => value_bytes,
+            };
+            let prev_bytes = self.tree.get(&to_bytes)?;
+            let new_bytes = Some(value_bytes.clone());
+            if self.tree.cas(to_bytes.clone(), prev_bytes.clone(), new_bytes.clone())?.is_err() {
+                return Ok(None);
+            }
+            if self.tree.cas(from_bytes.clone(), Some(value_bytes), None)?.is_ok() {
+                return Ok(Some(true));
+            }
+            // Only restore `to` if no other writer has replaced it since.
+            let _ = self.tree.cas(to_bytes.clone(), new_bytes, prev_bytes)?;
+            Ok(None)
+        })
+    }
+
+    /// Iterate over all key value pairs in the table, removing each from the table as it is
+    /// yielded.
+    ///
+    /// The table is left empty once the iterator is fully consumed.
+    pub fn drain(&self) -> Result<Drain<'a, T>> {
+        let iter_bytes = self.iter_bytes()?;
+        let tree: &'a Store = self.tree;
+        let _table = PhantomData;
+        Ok(Drain { iter_bytes, tree, _table })
+    }
+
+    /// Repeatedly call `f` until it succeeds, up to a maximum of `max` attempts.
+    ///
+    /// `f` should return `None` when an attempt fails due to contention (e.g. a failed
+    /// compare-and-swap) and may be retried. Returns `Error::RetryExhausted` if all `max` attempts
+    /// fail.
+    pub fn with_retries<F, R>(&self, max: usize, mut f: F) -> Result<R>
+    where
+        F: FnMut() -> Result<Option<R>>,
+    {
+        for _ in 0..max {
+            if let Some(r) = f()? {
+                return Ok(r);
+            }
+        }
+        Err(Error::RetryExhausted)
+    }
+
+    /// Flush all buffered writes to disk, blocking until they are durable.
+    ///
+    /// Once this returns, every write that was made to the underlying tree before the call
+    /// (including those made via other tables) will survive a restart. Writes made concurrently
+    /// with the call may or may not be included.
+    ///
+    /// **Note:** The pinned version of sled does not expose a way of requesting compaction, so
+    /// this currently only flushes.
+    pub fn sync(&self) -> Result<()> {
+        self.tree.flush()
+    }
+
+    /// Remove the entry for the given key only if its current value is `expected`.
+    ///
+    /// Returns whether or not the entry was removed. This is performed atomically via
+    /// compare-and-swap, so a value that has since been changed by another writer is never
+    /// removed.
+    ///
+    /// Values are compared in their encoded form.
+    pub fn compare_and_delete(&self, key: &T::Key, expected: &T::Value) -> Result<bool> {
+        let key_bytes = self.key_bytes(key)?;
+        let expected_bytes = write_value::<T>(expected)?;
+        let deleted = self.tree.cas(key_bytes, Some(expected_bytes), None)?.is_ok();
+        Ok(deleted)
+    }
 }
 
 // Trait implementations.
@@ -260,61 +1613,156 @@ impl<T> Key for T where T: for<'de> Deserialize<'de> + Serialize {}
 
 impl<T> Value for T where T: for<'de> Deserialize<'de> + Serialize {}
 
-impl<'a, T> From<&'a sled::Tree> for Reader<'a, T> {
-    fn from(tree: &'a sled::Tree) -> Self {
-        let _table = PhantomData;
-        Reader { tree, _table }
+impl<'a, T, S> From<&'a S> for Reader<'a, T, S>
+where
+    T: Table,
+    S: Store,
+{
+    fn from(tree: &'a S) -> Self {
+        Reader::new(tree)
     }
 }
 
-impl<'a, T> From<&'a sled::Tree> for Writer<'a, T> {
-    fn from(tree: &'a sled::Tree) -> Self {
+impl<'a, T, S> From<&'a S> for Writer<'a, T, S>
+where
+    T: Table,
+    S: Store,
+{
+    fn from(tree: &'a S) -> Self {
         let reader = tree.into();
         Writer { reader }
     }
 }
 
-impl<'a, T> From<Writer<'a, T>> for Reader<'a, T> {
-    fn from(writer: Writer<'a, T>) -> Self {
+impl<'a, T, S> From<Writer<'a, T, S>> for Reader<'a, T, S> {
+    fn from(writer: Writer<'a, T, S>) -> Self {
         writer.reader
     }
 }
 
-impl<'a, T> Clone for Reader<'a, T> {
+impl<'a, T, S> Clone for Reader<'a, T, S> {
     fn clone(&self) -> Self {
-        let tree = self.tree;
-        let _table = PhantomData;
-        Reader { tree, _table }
+        Reader {
+            tree: self.tree,
+            id_bytes: self.id_bytes.clone(),
+            #[cfg(all(debug_assertions, feature = "registry"))]
+            registration: self.registration.clone(),
+            _table: PhantomData,
+        }
     }
 }
 
-impl<'a, T> Clone for Writer<'a, T> {
+impl<'a, T, S> Clone for Writer<'a, T, S> {
     fn clone(&self) -> Self {
         let reader = self.reader.clone();
         Writer { reader }
     }
 }
 
-impl<'a, T> ops::Deref for Writer<'a, T> {
-    type Target = Reader<'a, T>;
+impl<'a, T, S> ops::Deref for Writer<'a, T, S> {
+    type Target = Reader<'a, T, S>;
     fn deref(&self) -> &Self::Target {
         &self.reader
     }
 }
 
+impl<'a, 'b, T, S> IntoIterator for &'b Reader<'a, T, S>
+where
+    T: Table,
+    S: Store,
+{
+    type Item = Result<(T::Key, T::Value)>;
+    type IntoIter = IntoIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        let iter = self.iter().map_err(Some);
+        IntoIter { iter }
+    }
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// The raw key bytes of the last yielded entry, if any.
+    ///
+    /// These may be persisted and later passed to `Reader::iter_from_raw` in order to resume
+    /// iteration after this entry.
+    pub fn cursor(&self) -> Option<&[u8]> {
+        self.iter_bytes.cursor()
+    }
+}
+
+impl<'a, T> PeekableIter<'a, T>
+where
+    T: Table,
+{
+    /// A reference to the next entry without consuming it.
+    ///
+    /// The entry is decoded upon the first call and yielded by the following call to `next`, so
+    /// a decoding error is also only yielded once.
+    pub fn peek(&mut self) -> Option<&Result<(T::Key, T::Value)>> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.iter.next());
+        }
+        match self.peeked {
+            Some(Some(ref res)) => Some(res),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> IterBytes<'a> {
+    /// The raw key bytes of the last yielded entry, if any.
+    pub fn cursor(&self) -> Option<&[u8]> {
+        self.cursor.as_ref().map(|bytes| &bytes[..])
+    }
+}
+
 impl<'a> Iterator for IterBytes<'a> {
     type Item = Result<(Vec<u8>, Vec<u8>)>;
     fn next(&mut self) -> Option<Self::Item> {
         let (id_key_bytes, value_bytes) = match self.iter.next() {
             None => return None,
-            Some(Err(err)) => return Some(Err(err.into())),
+            Some(Err(err)) => return Some(Err(err)),
             Some(Ok(tuple)) => tuple,
         };
-        if self.id_bytes != &id_key_bytes[..self.id_bytes.len()] {
+        let id_len = self.id_bytes.len();
+        if !id_key_bytes.starts_with(&self.id_bytes)
+            || !id_key_bytes[id_len..].starts_with(&self.prefix_bytes)
+        {
             return None;
         }
+        if let Some(ref end_bytes) = self.end_bytes {
+            if id_key_bytes >= *end_bytes {
+                return None;
+            }
+        }
+        self.cursor = Some(id_key_bytes.clone());
         Some(Ok((id_key_bytes, value_bytes)))
     }
+
+    // The store cannot know where the table ends, so only its upper bound remains valid. This is
+    // unbounded for sled, whose iterators provide no hint.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, T> Iterator for IntoIter<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<(T::Key, T::Value)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter {
+            Ok(ref mut iter) => iter.next(),
+            Err(ref mut err) => err.take().map(Err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.iter {
+            Ok(ref iter) => iter.size_hint(),
+            Err(ref err) => (err.is_some() as usize, Some(err.is_some() as usize)),
+        }
+    }
 }
 
 impl<'a, T> Iterator for Iter<'a, T>
@@ -328,27 +1776,317 @@ where
             Ok(kv) => kv,
         };
         let id_len = self.iter_bytes.id_bytes.len();
-        let key_bytes = &id_key_bytes[id_len..];
-        let key = match bytekey::deserialize(key_bytes) {
-            Err(err) => return Some(Err(err.into())),
-            Ok(key) => key,
+        Some(read_iter_entry::<T>(&id_key_bytes, id_len, &value_bytes))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter_bytes.size_hint()
+    }
+}
+
+impl<'a, T> Iterator for PeekableIter<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<(T::Key, T::Value)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        match self.peeked {
+            None => (lo, hi),
+            Some(None) => (0, Some(0)),
+            Some(Some(_)) => (lo.saturating_add(1), hi.and_then(|hi| hi.checked_add(1))),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, T> futures::Stream for TableStream<'a, T>
+where
+    T: Table,
+{
+    type Item = (T::Key, T::Value);
+    type Error = Error;
+    fn poll(&mut self) -> futures::Poll<Option<Self::Item>, Self::Error> {
+        match self.iter.next() {
+            None => Ok(futures::Async::Ready(None)),
+            Some(res) => res.map(|kv| futures::Async::Ready(Some(kv))),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Values<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<T::Value>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter_bytes.next()? {
+            Err(err) => Some(Err(err)),
+            Ok((_, value_bytes)) => Some(read_value::<T>(&value_bytes)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter_bytes.size_hint()
+    }
+}
+
+impl<'a, T> Iterator for ChunkedIter<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<Vec<(T::Key, T::Value)>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.err.take() {
+            return Some(Err(err));
+        }
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        while chunk.len() < self.chunk_size {
+            match self.iter.next() {
+                None => break,
+                Some(Ok(kv)) => chunk.push(kv),
+                Some(Err(err)) => match chunk.is_empty() {
+                    true => return Some(Err(err)),
+                    false => {
+                        self.err = Some(err);
+                        break;
+                    }
+                },
+            }
+        }
+        match chunk.is_empty() {
+            true => None,
+            false => Some(Ok(chunk)),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ValuesRange<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<T::Value>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key_bytes, value_bytes) = match self.iter_bytes.next()? {
+            Err(err) => return Some(Err(err)),
+            Ok(kv) => kv,
+        };
+        if key_bytes >= self.end_bytes {
+            return None;
+        }
+        Some(read_value::<T>(&value_bytes))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter_bytes.size_hint()
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<(T::Key, T::Value)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id_key_bytes, value_bytes) = match self.iter_bytes.next()? {
+            Err(err) => return Some(Err(err)),
+            Ok(kv) => kv,
+        };
+        let id_len = self.iter_bytes.id_bytes.len();
+        let kv = match read_iter_entry::<T>(&id_key_bytes, id_len, &value_bytes) {
+            Err(err) => return Some(Err(err)),
+            Ok(kv) => kv,
+        };
+        match self.tree.del(&id_key_bytes) {
+            Err(err) => Some(Err(err)),
+            Ok(_) => Some(Ok(kv)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter_bytes.size_hint()
+    }
+}
+
+impl<'a, T, F, U> Iterator for MapValues<'a, T, F>
+where
+    T: Table,
+    F: FnMut(T::Value) -> U,
+{
+    type Item = Result<(T::Key, U)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            Err(err) => Some(Err(err)),
+            Ok((k, v)) => Some(Ok((k, (self.f)(v)))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, F> Iterator for ScanWhile<'a, T, F>
+where
+    T: Table,
+    F: FnMut(&T::Key, &T::Value) -> bool,
+{
+    type Item = Result<(T::Key, T::Value)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (k, v) = match self.iter.as_mut()?.next()? {
+            Err(err) => return Some(Err(err)),
+            Ok(kv) => kv,
         };
-        let value = match bincode::deserialize(&value_bytes) {
-            Err(err) => return Some(Err(err.into())),
-            Ok(value) => value,
+        if !(self.pred)(&k, &v) {
+            self.iter = None;
+            return None;
+        }
+        Some(Ok((k, v)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.iter {
+            None => (0, Some(0)),
+            Some(ref iter) => (0, iter.size_hint().1),
+        }
+    }
+}
+
+impl<'a> Iterator for RevIterBytes<'a> {
+    type Item = Result<(Vec<u8>, Vec<u8>)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let key_bytes = self.key_bytes.take()?;
+        let pred = byte_search::find_pred(self.tree, &key_bytes, self.inclusive);
+        let (id_key_bytes, value_bytes) = match pred {
+            Err(err) => return Some(Err(err)),
+            Ok(None) => return None,
+            Ok(Some(kv)) => kv,
         };
-        Some(Ok((key, value)))
+        if !id_key_bytes.starts_with(&self.id_bytes) {
+            return None;
+        }
+        self.key_bytes = Some(id_key_bytes.clone());
+        self.inclusive = false;
+        Some(Ok((id_key_bytes, value_bytes)))
+    }
+}
+
+impl<'a, T> Iterator for KeysRev<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<T::Key>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id_key_bytes, _) = match self.iter_bytes.next()? {
+            Err(err) => return Some(Err(err)),
+            Ok(kv) => kv,
+        };
+        let id_len = self.iter_bytes.id_bytes.len();
+        Some(bytekey::deserialize(&id_key_bytes[id_len..]).map_err(Into::into))
+    }
+}
+
+impl<'a, T> Iterator for RevIter<'a, T>
+where
+    T: Table,
+{
+    type Item = Result<(T::Key, T::Value)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id_key_bytes, value_bytes) = match self.iter_bytes.next()? {
+            Err(err) => return Some(Err(err)),
+            Ok(kv) => kv,
+        };
+        let id_len = self.iter_bytes.id_bytes.len();
+        Some(read_iter_entry::<T>(&id_key_bytes, id_len, &value_bytes))
     }
 }
 
 // Error implementations.
 
+impl Error {
+    /// Whether or not the error was produced by the tree itself, e.g. an I/O failure.
+    ///
+    /// Such errors may be transient, in which case the operation might succeed if retried.
+    pub fn is_io(&self) -> bool {
+        match *self {
+            Error::Sled(sled::Error::Corruption { .. }) => false,
+            Error::Sled(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether or not the error indicates that stored data is invalid.
+    ///
+    /// This includes corruption detected by the tree, checksum mismatches and failures to decode
+    /// the keys and values read while iterating over a table. Errors that may equally occur while
+    /// encoding, such as a bincode `SizeLimit`, are not considered corruption.
+    pub fn is_corruption(&self) -> bool {
+        match *self {
+            Error::Sled(sled::Error::Corruption { .. }) | Error::ChecksumMismatch => true,
+            Error::DecodeKey { .. } | Error::DecodeValue { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether or not the error occurred while encoding or decoding a key or value.
+    pub fn is_codec(&self) -> bool {
+        match *self {
+            Error::Sled(_) => false,
+            Error::Bincode(_) | Error::Bytekey(_) | Error::Compression(_) | Error::Codec(_) => true,
+            Error::ChecksumMismatch | Error::RetryExhausted | Error::ZeroStep => false,
+            Error::DecodeKey { ref source, .. } | Error::DecodeValue { ref source, .. } => {
+                source.is_codec()
+            }
+        }
+    }
+
+    /// The inner sled error, if the error was produced by the tree.
+    pub fn as_sled(&self) -> Option<&sled::Error<()>> {
+        match *self {
+            Error::Sled(ref err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// The inner bincode error, if the error occurred while encoding or decoding a value.
+    pub fn as_bincode(&self) -> Option<&bincode::Error> {
+        match *self {
+            Error::Bincode(ref err) => Some(err),
+            Error::DecodeValue { ref source, .. } => source.as_bincode(),
+            _ => None,
+        }
+    }
+
+    /// The inner bytekey error, if the error occurred while encoding or decoding a key.
+    pub fn as_bytekey(&self) -> Option<&bytekey::Error> {
+        match *self {
+            Error::Bytekey(ref err) => Some(err),
+            Error::DecodeKey { ref source, .. } => source.as_bytekey(),
+            _ => None,
+        }
+    }
+}
+
 impl StdError for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Sled(ref err) => err.description(),
             Error::Bincode(ref err) => err.description(),
             Error::Bytekey(ref err) => err.description(),
+            Error::Compression(ref err) => err.description(),
+            Error::Codec(ref err) => err.description(),
+            Error::ChecksumMismatch => "the checksum of a stored value does not match its bytes",
+            Error::RetryExhausted => "exceeded the maximum number of retries",
+            Error::ZeroStep => "the window `step` must be non-zero",
+            Error::DecodeKey { .. } => "failed to decode a key read from the tree",
+            Error::DecodeValue { .. } => "failed to decode a value read from the tree",
         }
     }
 
@@ -357,6 +2095,12 @@ impl StdError for Error {
             Error::Sled(ref err) => Some(err),
             Error::Bincode(ref err) => Some(err),
             Error::Bytekey(ref err) => Some(err),
+            Error::Compression(ref err) => Some(err),
+            Error::Codec(ref err) => Some(&**err),
+            Error::ChecksumMismatch | Error::RetryExhausted | Error::ZeroStep => None,
+            Error::DecodeKey { ref source, .. } | Error::DecodeValue { ref source, .. } => {
+                Some(&**source)
+            }
         }
     }
 }
@@ -397,6 +2141,25 @@ impl From<bytekey::de::Error> for Error {
     }
 }
 
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        let kind = match e {
+            // Keep the kind of I/O errors produced by the tree.
+            Error::Sled(sled::Error::Io(err)) => return err,
+            Error::Sled(_) | Error::RetryExhausted => io::ErrorKind::Other,
+            Error::ZeroStep => io::ErrorKind::InvalidInput,
+            Error::Bincode(_)
+            | Error::Bytekey(_)
+            | Error::Compression(_)
+            | Error::Codec(_)
+            | Error::ChecksumMismatch
+            | Error::DecodeKey { .. }
+            | Error::DecodeValue { .. } => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
 // Pure functions.
 
 /// Write a key for table `T` to bytes.
@@ -409,6 +2172,219 @@ pub fn write_key<T: Table>(key: &T::Key) -> bytekey::Result<Vec<u8>> {
     Ok(key_bytes)
 }
 
+/// Write a value for table `T` to bytes.
+///
+/// The value is encoded via `T::Codec`, compressed if `T::COMPRESS` is enabled and then
+/// pre-pended with the `T::VERSION` byte if there is one. Finally, a CRC32 checksum of the bytes is
+/// appended if `T::CHECKSUM` is enabled.
+pub fn write_value<T: Table>(value: &T::Value) -> Result<Vec<u8>> {
+    let value_bytes = compress::<T>(T::Codec::encode::<T>(value)?)?;
+    let value_bytes = match T::VERSION {
+        None => value_bytes,
+        Some(version) => {
+            let mut versioned_bytes = Vec::with_capacity(1 + value_bytes.len());
+            versioned_bytes.push(version);
+            versioned_bytes.extend(value_bytes);
+            versioned_bytes
+        }
+    };
+    Ok(append_checksum::<T>(value_bytes))
+}
+
+/// Read a value for table `T` from the given bytes.
+///
+/// This is the inverse of `write_value`. Values written under a version other than `T::VERSION`
+/// are produced via `T::migrate`.
+pub fn read_value<T: Table>(value_bytes: &[u8]) -> Result<T::Value> {
+    let value_bytes = verify_checksum::<T>(value_bytes)?;
+    let (version, value_bytes) = match T::VERSION {
+        None => (None, value_bytes),
+        Some(_) => match value_bytes.split_first() {
+            None => return Err(unexpected_eof()),
+            Some((&version, value_bytes)) => (Some(version), value_bytes),
+        },
+    };
+    let value_bytes = decompress::<T>(value_bytes)?;
+    match version {
+        Some(version) if Some(version) != T::VERSION => T::migrate(version, &value_bytes),
+        _ => T::Codec::decode::<T>(&value_bytes),
+    }
+}
+
+/// A sled merge operator that applies `T::merge` to the values of table `T`.
+///
+/// This should be registered with the tree's config in order to use `Writer::merge`, e.g.
+/// `ConfigBuilder::new().merge_operator(merge_operator::<T>)`. As a tree may only have a single
+/// merge operator, trees with more than one merged table may dispatch to each table's operator by
+/// comparing the `key` against the bytes of each `T::ID`.
+///
+/// As merge operators cannot fail, the existing value is left unchanged if either value cannot be
+/// read.
+pub fn merge_operator<T: Table>(
+    key: &[u8],
+    existing: Option<&[u8]>,
+    operand: &[u8],
+) -> Option<Vec<u8>> {
+    let _ = key;
+    let unchanged = || existing.map(|bytes| bytes.to_vec());
+    let existing_value = match existing.map(read_value::<T>) {
+        None => None,
+        Some(Ok(value)) => Some(value),
+        Some(Err(_)) => return unchanged(),
+    };
+    let operand_value = match read_value::<T>(operand) {
+        Ok(value) => value,
+        Err(_) => return unchanged(),
+    };
+    match T::merge(existing_value, operand_value) {
+        None => None,
+        Some(value) => write_value::<T>(&value).ok().or_else(unchanged),
+    }
+}
+
+// Read a key/value pair for table `T` from the given bytes, where `key_bytes` excludes the ID.
+fn read_entry<T: Table>(key_bytes: &[u8], value_bytes: &[u8]) -> Result<(T::Key, T::Value)> {
+    let key = bytekey::deserialize(key_bytes)?;
+    let value = read_value::<T>(value_bytes)?;
+    Ok((key, value))
+}
+
+// The size on disk of an entry with the given key bytes (including the table ID) and value bytes.
+fn entry_size_bytes(key_bytes: &[u8], value_bytes: &[u8]) -> usize {
+    key_bytes.len() + value_bytes.len()
+}
+
+// Read an entry yielded by an `IterBytes`, attaching the raw bytes to any failure to decode it.
+fn read_iter_entry<T: Table>(
+    id_key_bytes: &[u8],
+    id_len: usize,
+    value_bytes: &[u8],
+) -> Result<(T::Key, T::Value)> {
+    let (id_bytes, key_bytes) = id_key_bytes.split_at(id_len);
+    let key = match bytekey::deserialize(key_bytes) {
+        Ok(key) => key,
+        Err(err) => {
+            return Err(Error::DecodeKey {
+                id_bytes: id_bytes.to_vec(),
+                key_bytes: key_bytes.to_vec(),
+                source: Box::new(err.into()),
+            });
+        }
+    };
+    match read_value::<T>(value_bytes) {
+        Ok(value) => Ok((key, value)),
+        Err(err) => Err(Error::DecodeValue {
+            id_bytes: id_bytes.to_vec(),
+            key_bytes: key_bytes.to_vec(),
+            value_bytes: value_bytes.to_vec(),
+            source: Box::new(err),
+        }),
+    }
+}
+
+// The smallest bytes that follow every key beginning with the given prefix, if any.
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let len = prefix.iter().rposition(|&b| b != 0xFF)? + 1;
+    let mut end = prefix[..len].to_vec();
+    end[len - 1] += 1;
+    Some(end)
+}
+
+// Produce the next key/value pair from the given iterator, if any.
+fn next_bytes(iter: &mut IterBytes) -> Result<Option<(Vec<u8>, Vec<u8>)>> {
+    match iter.next() {
+        None => Ok(None),
+        Some(Err(err)) => Err(err),
+        Some(Ok(kv)) => Ok(Some(kv)),
+    }
+}
+
+// Read the `Id` from the start of the given key bytes along with the number of bytes it spans.
+//
+// The `Id` is decoded from the start of the key, after which re-serializing it yields the length
+// of its prefix as the serialized `Id` is self-delimiting.
+fn read_id<I: Id>(key_bytes: &[u8]) -> Result<(I, usize)> {
+    let id = bytekey::deserialize::<I>(key_bytes)?;
+    let id_len = bytekey::serialize(&id)?.len();
+    Ok((id, id_len))
+}
+
+// The error returned when value bytes end before they were expected to.
+fn unexpected_eof() -> Error {
+    let err = io::Error::from(io::ErrorKind::UnexpectedEof);
+    Error::Bincode(Box::new(bincode::ErrorKind::Io(err)))
+}
+
+#[cfg(feature = "compress")]
+fn compress<T: Table>(bytes: Vec<u8>) -> Result<Vec<u8>> {
+    match T::COMPRESS {
+        false => Ok(bytes),
+        true => zstd::encode_all(&bytes[..], zstd::DEFAULT_COMPRESSION_LEVEL)
+            .map_err(Error::Compression),
+    }
+}
+
+#[cfg(not(feature = "compress"))]
+fn compress<T: Table>(bytes: Vec<u8>) -> Result<Vec<u8>> {
+    Ok(bytes)
+}
+
+#[cfg(feature = "compress")]
+fn decompress<T: Table>(bytes: &[u8]) -> Result<Cow<[u8]>> {
+    match T::COMPRESS {
+        false => Ok(Cow::Borrowed(bytes)),
+        true => zstd::decode_all(bytes).map(Cow::Owned).map_err(Error::Compression),
+    }
+}
+
+#[cfg(not(feature = "compress"))]
+fn decompress<T: Table>(bytes: &[u8]) -> Result<Cow<[u8]>> {
+    Ok(Cow::Borrowed(bytes))
+}
+
+// The number of bytes in a value's trailing checksum.
+#[cfg(feature = "checksum")]
+const CHECKSUM_LEN: usize = 4;
+
+#[cfg(feature = "checksum")]
+fn append_checksum<T: Table>(mut bytes: Vec<u8>) -> Vec<u8> {
+    if T::CHECKSUM {
+        let checksum = crc32fast::hash(&bytes);
+        bytes.extend((0..CHECKSUM_LEN).map(|i| (checksum >> (i * 8)) as u8));
+    }
+    bytes
+}
+
+#[cfg(not(feature = "checksum"))]
+fn append_checksum<T: Table>(bytes: Vec<u8>) -> Vec<u8> {
+    bytes
+}
+
+// Verify and strip the trailing checksum from the given value bytes.
+#[cfg(feature = "checksum")]
+fn verify_checksum<T: Table>(bytes: &[u8]) -> Result<&[u8]> {
+    if !T::CHECKSUM {
+        return Ok(bytes);
+    }
+    if bytes.len() < CHECKSUM_LEN {
+        return Err(Error::ChecksumMismatch);
+    }
+    let (bytes, checksum_bytes) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+    let checksum = checksum_bytes
+        .iter()
+        .enumerate()
+        .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (i * 8));
+    match crc32fast::hash(bytes) == checksum {
+        true => Ok(bytes),
+        false => Err(Error::ChecksumMismatch),
+    }
+}
+
+#[cfg(not(feature = "checksum"))]
+fn verify_checksum<T: Table>(bytes: &[u8]) -> Result<&[u8]> {
+    Ok(bytes)
+}
+
 /// Calculate the size of the given sled tree in bytes.
 ///
 /// This is calculated by iterating over and summing all elements in the tree.
@@ -420,3 +2396,37 @@ pub fn tree_size_bytes(tree: &sled::Tree) -> sled::DbResult<usize, ()> {
     }
     Ok(bytes)
 }
+
+/// Remove every entry from every table within the given store.
+///
+/// This is equivalent to `Store::clear`.
+pub fn clear_tree<S: Store>(tree: &S) -> Result<()> {
+    tree.clear()
+}
+
+/// Summarise the tables within the given store by counting the entries within each.
+///
+/// Yields each table's `Id` along with its number of entries, ordered by the bytes of the `Id`.
+/// Only the leading `Id` of each table's first key is decoded.
+///
+/// **Note:** The serialized `Id` must be self-delimiting (e.g. integers, enums or strings) so that
+/// the end of the `Id` can be found within each key.
+pub fn table_summary<I, S>(tree: &S) -> Result<Vec<(I, usize)>>
+where
+    I: Id,
+    S: Store,
+{
+    let mut summary: Vec<(I, usize)> = vec![];
+    let mut id_bytes = vec![];
+    for res in tree.scan(&[]) {
+        let (k, _) = res?;
+        if !summary.is_empty() && k.starts_with(&id_bytes) {
+            summary.last_mut().expect("summary is non-empty").1 += 1;
+            continue;
+        }
+        let (id, id_len) = read_id::<I>(&k)?;
+        id_bytes = k[..id_len].to_vec();
+        summary.push((id, 1));
+    }
+    Ok(summary)
+}
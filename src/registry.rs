@@ -0,0 +1,94 @@
+//! A debug-only registry of the tables in use within each store, used to detect tables that
+//! accidentally share the same prefix space.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+// A table in use within some store, along with the number of live registrations for it.
+struct Entry {
+    id_bytes: Vec<u8>,
+    name: &'static str,
+    count: usize,
+}
+
+lazy_static! {
+    // The tables in use within each store, keyed by the address of the store.
+    static ref REGISTRY: Mutex<HashMap<usize, Vec<Entry>>> = Mutex::new(HashMap::new());
+}
+
+/// The registration of a table within a store, released when dropped.
+///
+/// As a store outlives the readers of its tables, the address of a store is never reused while
+/// the tables within it are registered.
+#[derive(Debug)]
+pub struct Registration {
+    store: usize,
+    id_bytes: Vec<u8>,
+    name: &'static str,
+}
+
+/// Register the table with the given serialized ID and type name as in use within the store at
+/// the given address.
+///
+/// Panics if a different table is in use within the same store whose ID bytes are equal to, or a
+/// prefix of, `id_bytes` or vice versa, as the keys of each table could then be read as the other.
+pub fn register(store: usize, id_bytes: &[u8], name: &'static str) -> Registration {
+    let mut registry = lock();
+    let entries = registry.entry(store).or_insert_with(Vec::new);
+    let mut entry_index = None;
+    for (i, entry) in entries.iter().enumerate() {
+        if entry.name == name && entry.id_bytes == id_bytes {
+            entry_index = Some(i);
+        } else if entry.id_bytes.starts_with(id_bytes) || id_bytes.starts_with(&entry.id_bytes) {
+            panic!(
+                "table ID collision: `{}` (serialized as {:?}) and `{}` (serialized as {:?}) share \
+                 the same prefix space within the same store",
+                entry.name, entry.id_bytes, name, id_bytes,
+            );
+        }
+    }
+    match entry_index {
+        Some(i) => entries[i].count += 1,
+        None => {
+            let id_bytes = id_bytes.to_vec();
+            entries.push(Entry { id_bytes, name, count: 1 });
+        }
+    }
+    let id_bytes = id_bytes.to_vec();
+    Registration { store, id_bytes, name }
+}
+
+// Lock the registry, ignoring poisoning by a collision panic as the registry is left unchanged.
+fn lock() -> MutexGuard<'static, HashMap<usize, Vec<Entry>>> {
+    REGISTRY.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+impl Clone for Registration {
+    fn clone(&self) -> Self {
+        register(self.store, &self.id_bytes, self.name)
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        let mut registry = lock();
+        let remove_store = match registry.get_mut(&self.store) {
+            None => return,
+            Some(entries) => {
+                let position = entries.iter().position(|entry| {
+                    entry.name == self.name && entry.id_bytes == self.id_bytes
+                });
+                if let Some(i) = position {
+                    entries[i].count -= 1;
+                    if entries[i].count == 0 {
+                        entries.remove(i);
+                    }
+                }
+                entries.is_empty()
+            }
+        };
+        if remove_store {
+            registry.remove(&self.store);
+        }
+    }
+}
@@ -29,6 +29,18 @@ where
     reverse_table: ::Reader<'a, T::ReverseTable>,
 }
 
+/// A summary of the consistency between a reversible table and its reverse table, as produced by
+/// `Reader::bijection_stats`.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BijectionStats {
+    /// The number of entries whose inverse exists within the reverse table.
+    pub matched: usize,
+    /// The number of entries within the table whose inverse is missing from the reverse table.
+    pub forward_only: usize,
+    /// The number of entries within the reverse table whose inverse is missing from the table.
+    pub reverse_only: usize,
+}
+
 // Inherent implementations.
 
 impl<'a, T> Writer<'a, T>
@@ -51,6 +63,32 @@ where
         Ok(())
     }
 
+    /// Set the given **key** to the new **value**, replacing any existing mappings for either.
+    ///
+    /// Unlike `set`, this does not panic on conflicts. The existing key for **value** and the
+    /// existing value for **key** are both removed along with their reverse entries before the
+    /// new pair is inserted, keeping the mapping between the tables one-to-one.
+    ///
+    /// Returns the value that was previously associated with **key**, if any.
+    ///
+    /// **Note:** sled does not yet support transactions, so this is performed as a sequence of
+    /// writes. These are ordered so that every intermediate state remains a partial bijection: no
+    /// two keys share a value and no entry is contradicted by its reverse entry, though an entry
+    /// may briefly lack its reverse entry.
+    pub fn replace(&self, key: &T::Key, value: &T::Value) -> Result<Option<T::Value>> {
+        let old_value = self.table.get(key)?;
+        if let Some(old_key) = self.reverse_table.get(value)? {
+            self.table.del(&old_key)?;
+            self.reverse_table.del(value)?;
+        }
+        if let Some(ref old_value) = old_value {
+            self.reverse_table.del(old_value)?;
+        }
+        self.table.set(key, value)?;
+        self.reverse_table.set(value, key)?;
+        Ok(old_value)
+    }
+
     /// Remove the entry for the given **key** from the table.
     ///
     /// Also removes the reverse entry from the reverse table.
@@ -62,6 +100,25 @@ where
         Ok(maybe_value)
     }
 
+    /// Remove the entry for the given **value** from the reverse table.
+    ///
+    /// Also removes the entry for the associated key from the table, returning the removed key.
+    /// Unlike deleting via `inv`, this does not require that `T::ReverseTable` is itself
+    /// `Reversible`.
+    ///
+    /// **Note:** sled does not yet support transactions, so the key is looked up via the reverse
+    /// table and each direction is then removed separately. As within `replace`, the entry within
+    /// the table is removed first, so if the second removal fails the reverse entry is left
+    /// without its entry, which is removed by calling this again.
+    pub fn del_by_value(&self, value: &T::Value) -> Result<Option<T::Key>> {
+        let maybe_key = self.reverse_table.get(value)?;
+        if let Some(ref key) = maybe_key {
+            self.table.del(key)?;
+            self.reverse_table.del(value)?;
+        }
+        Ok(maybe_key)
+    }
+
     /// Return the inverse of this table.
     pub fn inv(&self) -> Writer<'a, T::ReverseTable>
     where
@@ -73,6 +130,62 @@ where
     }
 }
 
+impl<'a, T> Reader<'a, T>
+where
+    T: Reversible,
+{
+    /// Iterate over all value/key pairs ordered by value.
+    ///
+    /// This walks the reverse table directly, yielding each entry as `(value, key)`.
+    pub fn iter_by_value(&self) -> Result<::Iter<'a, T::ReverseTable>> {
+        self.reverse_table.iter()
+    }
+
+    /// Count the entries that are consistent between the table and its reverse table, along with
+    /// those that exist in only one of the two.
+    ///
+    /// Each table is scanned once. The entries of the table are ordered by the encoded bytes of
+    /// their values and merged with the entries of the reverse table, which are ordered by the
+    /// same bytes, so no point lookups are made. Memory use is proportional to the size of the
+    /// table. Neither table is modified, so this may be used to monitor the health of the mapping.
+    pub fn bijection_stats(&self) -> Result<BijectionStats>
+    where
+        T::Key: PartialEq,
+    {
+        let mut stats = BijectionStats::default();
+        let mut forward = vec![];
+        for res in self.table.iter()? {
+            let (key, value) = res?;
+            forward.push((self.reverse_table.key_bytes(&value)?, key));
+        }
+        forward.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut forward = forward.into_iter().peekable();
+        for res in self.reverse_table.iter_bytes()? {
+            let (value_key_bytes, key_bytes) = res?;
+            // Entries whose values precede the reverse entry have no inverse.
+            while forward.peek().map_or(false, |&(ref bytes, _)| *bytes < value_key_bytes) {
+                forward.next();
+                stats.forward_only += 1;
+            }
+            let key = ::read_value::<T::ReverseTable>(&key_bytes)?;
+            let mut matched = false;
+            while forward.peek().map_or(false, |&(ref bytes, _)| *bytes == value_key_bytes) {
+                let (_, forward_key) = forward.next().expect("no peeked entry");
+                match !matched && forward_key == key {
+                    true => matched = true,
+                    false => stats.forward_only += 1,
+                }
+            }
+            match matched {
+                true => stats.matched += 1,
+                false => stats.reverse_only += 1,
+            }
+        }
+        stats.forward_only += forward.count();
+        Ok(stats)
+    }
+}
+
 impl<'a, T> Reader<'a, T>
 where
     T: Reversible,
@@ -0,0 +1,206 @@
+//! Items related to the ordered key/value stores within which tables may be kept.
+
+use sled;
+use std;
+use std::collections::BTreeMap;
+use std::ops::Bound;
+use std::sync::RwLock;
+use Result;
+
+/// An iterator yielding key/value pairs from a **Store** in ascending key order.
+pub type StoreIter<'a> = Box<Iterator<Item = Result<(Vec<u8>, Vec<u8>)>> + 'a>;
+
+/// An ordered key/value store within which tables may be kept.
+///
+/// This is implemented for `sled::Tree` along with the in-memory `MemStore`, allowing code that
+/// uses tables to be tested without starting a `sled::Tree`.
+pub trait Store {
+    /// Retrieve the value for the given key if it exists.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
+    /// Set the given key to the given value.
+    fn set(&self, key: Vec<u8>, value: Vec<u8>) -> Result<()>;
+    /// Remove the value for the given key, returning it if it existed.
+    fn del(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
+    /// Set the value of the given key to `new` if its current value is `old`.
+    ///
+    /// If the current value does not match `old`, it is returned within the inner `Err`.
+    fn cas(
+        &self,
+        key: Vec<u8>,
+        old: Option<Vec<u8>>,
+        new: Option<Vec<u8>>,
+    ) -> Result<std::result::Result<(), Option<Vec<u8>>>>;
+    /// Merge the given operand into the value for the given key via the store's merge operator.
+    fn merge(&self, key: Vec<u8>, value: Vec<u8>) -> Result<()>;
+    /// Remove every key/value pair from the store.
+    fn clear(&self) -> Result<()>;
+    /// Persist all buffered writes to the underlying storage.
+    fn flush(&self) -> Result<()>;
+    /// Iterate over all key/value pairs in ascending order, starting at the given key.
+    ///
+    /// The iterator's `size_hint` bounds the table iterators built upon it, so it should provide
+    /// an upper bound where one is cheaply known.
+    fn scan<'a>(&'a self, key: &[u8]) -> StoreIter<'a>;
+}
+
+/// An in-memory **Store** backed by a `BTreeMap`.
+#[derive(Debug, Default)]
+pub struct MemStore {
+    map: RwLock<BTreeMap<Vec<u8>, Vec<u8>>>,
+    merge_operator: Option<sled::MergeOperator>,
+}
+
+/// An iterator yielding key/value pairs from a **MemStore**.
+///
+/// Each step looks up the successor of the previously yielded key, so the store may be modified
+/// during iteration.
+struct MemIter<'a> {
+    store: &'a MemStore,
+    start: Bound<Vec<u8>>,
+}
+
+impl MemStore {
+    /// Create a new, empty **MemStore**.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a new, empty **MemStore** that merges values via the given merge operator.
+    pub fn with_merge_operator(merge_operator: sled::MergeOperator) -> Self {
+        let map = Default::default();
+        let merge_operator = Some(merge_operator);
+        MemStore { map, merge_operator }
+    }
+}
+
+impl Store for sled::Tree {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(sled::Tree::get(self, key)?)
+    }
+
+    fn set(&self, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        Ok(sled::Tree::set(self, key, value)?)
+    }
+
+    fn del(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(sled::Tree::del(self, key)?)
+    }
+
+    fn cas(
+        &self,
+        key: Vec<u8>,
+        old: Option<Vec<u8>>,
+        new: Option<Vec<u8>>,
+    ) -> Result<std::result::Result<(), Option<Vec<u8>>>> {
+        match sled::Tree::cas(self, key, old, new) {
+            Ok(()) => Ok(Ok(())),
+            Err(sled::Error::CasFailed(current)) => Ok(Err(current)),
+            Err(err) => Err(err.danger_cast().into()),
+        }
+    }
+
+    fn merge(&self, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        Ok(sled::Tree::merge(self, key, value)?)
+    }
+
+    fn clear(&self) -> Result<()> {
+        Ok(sled::Tree::clear(self)?)
+    }
+
+    fn flush(&self) -> Result<()> {
+        Ok(sled::Tree::flush(self)?)
+    }
+
+    fn scan<'a>(&'a self, key: &[u8]) -> StoreIter<'a> {
+        Box::new(sled::Tree::scan(self, key).map(|res| res.map_err(Into::into)))
+    }
+}
+
+impl Store for MemStore {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let map = self.map.read().expect("failed to acquire lock");
+        Ok(map.get(key).cloned())
+    }
+
+    fn set(&self, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        let mut map = self.map.write().expect("failed to acquire lock");
+        map.insert(key, value);
+        Ok(())
+    }
+
+    fn del(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let mut map = self.map.write().expect("failed to acquire lock");
+        Ok(map.remove(key))
+    }
+
+    fn cas(
+        &self,
+        key: Vec<u8>,
+        old: Option<Vec<u8>>,
+        new: Option<Vec<u8>>,
+    ) -> Result<std::result::Result<(), Option<Vec<u8>>>> {
+        let mut map = self.map.write().expect("failed to acquire lock");
+        let current = map.get(&key).cloned();
+        if current != old {
+            return Ok(Err(current));
+        }
+        match new {
+            None => map.remove(&key),
+            Some(new) => map.insert(key, new),
+        };
+        Ok(Ok(()))
+    }
+
+    fn merge(&self, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        let merge_operator = match self.merge_operator {
+            Some(merge_operator) => merge_operator,
+            None => {
+                let err = sled::Error::Unsupported("must set a merge_operator on MemStore".into());
+                return Err(err.into());
+            }
+        };
+        let mut map = self.map.write().expect("failed to acquire lock");
+        let merged = merge_operator(&key, map.get(&key).map(|v| &v[..]), &value);
+        match merged {
+            None => map.remove(&key),
+            Some(merged) => map.insert(key, merged),
+        };
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<()> {
+        let mut map = self.map.write().expect("failed to acquire lock");
+        map.clear();
+        Ok(())
+    }
+
+    // A **MemStore** has no underlying storage.
+    fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn scan<'a>(&'a self, key: &[u8]) -> StoreIter<'a> {
+        let start = Bound::Included(key.to_vec());
+        Box::new(MemIter { store: self, start })
+    }
+}
+
+impl<'a> Iterator for MemIter<'a> {
+    type Item = Result<(Vec<u8>, Vec<u8>)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = {
+            let map = self.store.map.read().expect("failed to acquire lock");
+            let range = (self.start.clone(), Bound::Unbounded);
+            let (key, value) = map.range::<Vec<u8>, _>(range).next()?;
+            (key.clone(), value.clone())
+        };
+        self.start = Bound::Excluded(key.clone());
+        Some(Ok((key, value)))
+    }
+
+    // The store may be modified during iteration, so only its current size bounds the remainder.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let map = self.store.map.read().expect("failed to acquire lock");
+        (0, Some(map.len()))
+    }
+}
@@ -1,6 +1,7 @@
-use {Result, Table};
+use {Result, Store, Table};
 use sled;
 use std::{self, ops};
+use std::marker::PhantomData;
 use unsigned_binary_search::UnsignedBinarySearchKey;
 
 /// An extension to the **Table** trait that ensures each entry in the table is timestamped using
@@ -8,6 +9,15 @@ use unsigned_binary_search::UnsignedBinarySearchKey;
 ///
 /// The resulting table may be searched via both its original key type and using `Self::Timestamp`
 /// as a key.
+///
+/// The table and its timestamp index are updated by separate writes, so every value has an entry
+/// within the index but the index may also hold stale entries that no longer match the timestamp
+/// of their key's value. These exist briefly while a write is in progress, or indefinitely if the
+/// process stops partway through a write. Methods that resolve values (such as `iter`, `scan` and
+/// `window`) skip stale entries, while those that only read the index (such as `min`, `max`,
+/// `succ_incl`, `pred`, `count`, `histogram` and `iter_keys_by_time`) may include them. The only
+/// exceptions to the index holding an entry for every value are the races documented by
+/// `Writer::gc_older_than` and `Writer::rename_key`.
 pub trait Timestamped: Table {
     /// The timestamp type used to distinguish between entries.
     type Timestamp: Timestamp;
@@ -17,12 +27,54 @@ pub trait Timestamped: Table {
     fn value_timestamp(value: &Self::Value) -> Self::Timestamp;
 }
 
+/// An alternative to **Timestamped** for tables whose values do not carry their own timestamp.
+///
+/// The timestamp of each entry is supplied explicitly when it is set via `ExplicitWriter::set`.
+/// The `Self` table maps each key to its value as usual, while the timestamp is held within the
+/// keys of `Self::TimestampTable` in order to index entries chronologically. As the timestamp of
+/// a key cannot be found within the index without a scan, `Self::KeyTimestampTable` maps each key
+/// to the timestamp of its entry.
+///
+/// As with **Timestamped**, the tables are updated by separate writes, so the index may hold stale
+/// entries that no longer match the timestamp of their key. These are skipped by `ExplicitIter`.
+pub trait TimestampedExplicit: Table {
+    /// The timestamp type supplied for each entry.
+    type Timestamp: Timestamp;
+    /// The table used to index the keys of the `Self` table by their timestamps.
+    type TimestampTable: Table<Id = Self::Id, Key = Key<Self::Timestamp, Self::Key>, Value = ()>;
+    /// The table that maps each key to the timestamp supplied for its entry.
+    type KeyTimestampTable: Table<Id = Self::Id, Key = Self::Key, Value = Self::Timestamp>;
+}
+
+/// An extension to the **Timestamped** trait for tables whose values may be re-timestamped.
+pub trait SetTimestamp: Timestamped {
+    /// Given a mutable reference to a table value, replace its timestamp.
+    fn set_value_timestamp(value: &mut Self::Value, timestamp: Self::Timestamp);
+}
+
+/// An extension to the **Timestamped** trait for tables whose entries expire once they exceed some
+/// age, such as caches.
+///
+/// Expired entries are not removed automatically, but may be swept via `Writer::expire`.
+pub trait Ttl: Timestamped {
+    /// The age beyond which an entry is considered expired, as a timestamp duration.
+    const TTL: Self::Timestamp;
+}
+
 /// Types that may be used as a timestamp within a **Timestamped** **Table**.
 pub trait Timestamp: MinKey + PartialEq + PartialOrd {
     /// Given some timestamp `self`, produce the next consecutive timestamp.
     ///
-    /// This is used for the non-inclusive successor table method.
-    fn next(&self) -> Self;
+    /// Returns `None` if `self` is the maximum possible timestamp. This is used for the
+    /// non-inclusive successor table method.
+    fn checked_next(&self) -> Option<Self>;
+
+    /// Given some timestamp `self`, produce the next consecutive timestamp.
+    ///
+    /// Panics if `self` is the maximum possible timestamp.
+    fn next(&self) -> Self {
+        self.checked_next().expect("no timestamp follows the maximum timestamp")
+    }
 }
 
 /// Keys that have a known minimum value.
@@ -31,6 +83,12 @@ pub trait MinKey: ::Key {
     fn min_key() -> Self;
 }
 
+/// Timestamps that may be subtracted from one another, such as to determine the age of an entry.
+pub trait CheckedSub: Sized {
+    /// Subtract `rhs` from `self`, returning `None` if the result cannot be represented.
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+}
+
 /// Types that may be used to describe a timestamp range.
 ///
 /// **Note:** This should be removed once `std::ops::RangeBounds` gets stabilised.
@@ -70,12 +128,31 @@ where
 
 /// Iterate over all entries within the table `T` ordered by the timestamp associated with each
 /// entry.
+///
+/// Entries may be yielded from either end, with iteration ending once both ends meet. Stale
+/// timestamp entries that do not match the timestamp of their key's value are skipped.
 pub struct Iter<'a, T>
 where
     T: Timestamped,
 {
     iter: ::Iter<'a, T::TimestampTable>,
     table: ::Reader<'a, T>,
+    // The index key bytes at which iteration began, bounding iteration from the back.
+    start_bytes: Vec<u8>,
+    // Walks the index in reverse, created upon the first call to `next_back`.
+    rev: Option<::RevIterBytes<'a>>,
+    // The key bytes of the last index entry read from the back.
+    back_cursor: Option<Vec<u8>>,
+}
+
+/// Iterate over the timestamp and key of all entries within the table `T` ordered by timestamp.
+///
+/// Values are not looked up within the table.
+pub struct KeysByTime<'a, T>
+where
+    T: Timestamped,
+{
+    iter: ::Iter<'a, T::TimestampTable>,
 }
 
 /// Iterate over all entries within the table `T` ordered by the timestamp associated with each
@@ -88,6 +165,80 @@ where
     end_exclusive: Option<T::Timestamp>,
 }
 
+/// Iterate over the timestamp and key of all entries within the table `T` ordered by timestamp, as
+/// long as the timestamp falls within the given timestamp bounds.
+///
+/// Values are not looked up within the table.
+pub struct RangeKeys<'a, T>
+where
+    T: Timestamped,
+{
+    iter: KeysByTime<'a, T>,
+    end_exclusive: Option<T::Timestamp>,
+}
+
+/// Iterate over all entries within the table `T` in descending order of the timestamp associated
+/// with each entry, as long as that timestamp falls within the given timestamp bounds.
+pub struct IterRangeRev<'a, T>
+where
+    T: Timestamped,
+{
+    iter: ::RevIter<'a, T::TimestampTable>,
+    table: ::Reader<'a, T>,
+    start_inclusive: Option<T::Timestamp>,
+    end_exclusive: Option<T::Timestamp>,
+}
+
+/// Groups entries within the table `T` into consecutive, fixed-size intervals of time.
+///
+/// Yields the start of each interval along with all entries whose timestamps fall within it.
+pub struct WindowIter<'a, T>
+where
+    T: Timestamped,
+{
+    iter: Iter<'a, T>,
+    step: T::Timestamp,
+    // The first entry of the next window, read while searching for the end of the last.
+    next: Option<(T::Timestamp, (T::Key, T::Value))>,
+}
+
+/// Read-only access to an explicitly timestamped table within a `sled::Tree`.
+///
+/// The table may be kept within any other **Store** via the `S` parameter.
+#[derive(Debug)]
+pub struct ExplicitReader<'a, T, S: 'a = sled::Tree>
+where
+    T: TimestampedExplicit,
+{
+    table: ::Writer<'a, T, S>,
+    timestamp_table: ::Writer<'a, T::TimestampTable, S>,
+    key_timestamp_table: ::Writer<'a, T::KeyTimestampTable, S>,
+}
+
+/// Read and write access to an explicitly timestamped table within a `sled::Tree`.
+///
+/// The table may be kept within any other **Store** via the `S` parameter.
+pub struct ExplicitWriter<'a, T, S: 'a = sled::Tree>
+where
+    T: TimestampedExplicit,
+{
+    reader: ExplicitReader<'a, T, S>,
+}
+
+/// Iterate over all entries within the explicitly timestamped table `T` ordered by the timestamp
+/// supplied for each.
+///
+/// Yields the timestamped key of each entry along with its value. Stale timestamp entries that do
+/// not match the timestamp of their key are skipped.
+pub struct ExplicitIter<'a, T, S: 'a = sled::Tree>
+where
+    T: TimestampedExplicit,
+{
+    iter: ::Iter<'a, T::TimestampTable>,
+    table: ::Reader<'a, T, S>,
+    key_timestamp_table: ::Reader<'a, T::KeyTimestampTable, S>,
+}
+
 // Reader implementations.
 
 impl<'a, T> Reader<'a, T>
@@ -106,6 +257,121 @@ where
         self.table.clone().into()
     }
 
+    /// Iterate over the timestamp and key of all entries ordered by timestamp.
+    ///
+    /// Unlike `iter`, only the timestamp index is read, avoiding a lookup of each value within the
+    /// table.
+    pub fn iter_keys_by_time(&self) -> Result<KeysByTime<'a, T>> {
+        let iter = self.timestamp_table.iter()?;
+        Ok(KeysByTime { iter })
+    }
+
+    /// Count the number of entries within the table.
+    ///
+    /// Only the key bytes of the timestamp index are walked, so neither keys nor values are
+    /// deserialized. An entry that is in the midst of being written may be counted twice if both
+    /// its old and new index entries exist at the time of the walk.
+    pub fn count(&self) -> Result<usize> {
+        let mut count = 0;
+        for res in self.timestamp_table.iter_bytes()? {
+            res?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Count the entries within each consecutive window of time that spans `step`.
+    ///
+    /// Windows are aligned to multiples of `step` as within `window`, and each is returned as its
+    /// starting timestamp along with its number of entries. Windows that contain no entries are
+    /// skipped.
+    ///
+    /// Only the timestamp index is read, so entries that are in the midst of being written may be
+    /// counted within the window of either their old or new timestamp.
+    ///
+    /// Returns `Error::ZeroStep` if `step` is zero.
+    pub fn histogram(&self, step: T::Timestamp) -> Result<Vec<(T::Timestamp, usize)>>
+    where
+        T::Timestamp: Clone
+            + CheckedSub
+            + ops::Sub<Output = T::Timestamp>
+            + ops::Rem<Output = T::Timestamp>,
+    {
+        check_step(&step)?;
+        let mut histogram: Vec<(T::Timestamp, usize)> = vec![];
+        for res in self.iter_keys_by_time()? {
+            let (timestamp, _) = res?;
+            let start = window_start(timestamp, &step);
+            match histogram.last_mut() {
+                Some(&mut (ref last_start, ref mut count)) if *last_start == start => *count += 1,
+                _ => histogram.push((start, 1)),
+            }
+        }
+        Ok(histogram)
+    }
+
+    /// Return the `n`th entry within the table, ordered by timestamp.
+    ///
+    /// Returns `None` if the table contains `n` or fewer entries.
+    ///
+    /// Stale timestamp entries are skipped rather than counted as within `iter`, so each entry
+    /// preceding the `n`th is looked up within the table in order to check that it is current.
+    pub fn nth_by_time(&self, n: usize) -> Result<Option<(T::Key, T::Value)>> {
+        let iter_bytes = self.timestamp_table.iter_bytes()?;
+        let start_bytes = iter_bytes.id_bytes.to_vec();
+        let _table = PhantomData;
+        let iter = ::Iter { iter_bytes, _table };
+        let table = self.table.clone().into();
+        let (rev, back_cursor) = (None, None);
+        match (Iter::<T> { iter, table, start_bytes, rev, back_cursor }).nth(n) {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(kv)) => Ok(Some(kv)),
+        }
+    }
+
+    // Look up the values for a batch of timestamp index entries in ascending order of key,
+    // returning the entries in the order of the given index entries.
+    //
+    // As within `Iter`, index entries that are not reflected by the table are skipped.
+    fn resolve_many(
+        &self,
+        index_keys: Vec<Key<T::Timestamp, T::Key>>,
+    ) -> Result<Vec<(T::Key, T::Value)>> {
+        let mut lookups = Vec::with_capacity(index_keys.len());
+        for (i, timestamped_key) in index_keys.iter().enumerate() {
+            lookups.push((self.table.key_bytes(&timestamped_key.key)?, i));
+        }
+        lookups.sort();
+        let mut values: Vec<Option<T::Value>> = index_keys.iter().map(|_| None).collect();
+        for (key_bytes, i) in lookups {
+            if let Some(value_bytes) = self.table.tree.get(&key_bytes)? {
+                values[i] = Some(::read_value::<T>(&value_bytes)?);
+            }
+        }
+        let mut entries = Vec::with_capacity(index_keys.len());
+        for (Key { timestamp, key }, value) in index_keys.into_iter().zip(values) {
+            match value {
+                Some(ref value) if T::value_timestamp(value) != timestamp => (),
+                Some(value) => entries.push((key, value)),
+                None => (),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// The time elapsed between the timestamp of the entry for the given **key** and `now`.
+    ///
+    /// Returns `None` if no entry exists for the key, or if the elapsed time cannot be
+    /// represented by `T::Timestamp`, e.g. when `now` precedes an unsigned timestamp.
+    pub fn age_since(&self, key: &T::Key, now: T::Timestamp) -> Result<Option<T::Timestamp>>
+    where
+        T::Timestamp: CheckedSub,
+    {
+        let value = self.get(key)?;
+        Ok(value.and_then(|value| now.checked_sub(&T::value_timestamp(&value))))
+    }
+
     /// Return the minimum timestamp entry within the table.
     ///
     /// Note that there may be more than one entry that exists for the returned timestamp.
@@ -128,8 +394,10 @@ where
     pub fn scan(&self, timestamp: T::Timestamp) -> Result<Iter<'a, T>> {
         let table = self.table.clone().into();
         let timestamped_key = Key { timestamp, key: MinKey::min_key() };
+        let start_bytes = self.timestamp_table.key_bytes(&timestamped_key)?;
         let iter = self.timestamp_table.scan(&timestamped_key)?;
-        Ok(Iter { table, iter })
+        let (rev, back_cursor) = (None, None);
+        Ok(Iter { table, iter, start_bytes, rev, back_cursor })
     }
 
     /// Iterate over all entries ordered by the timestamp associated with each as long as it falls
@@ -147,6 +415,127 @@ where
         })
     }
 
+    /// Iterate over the timestamp and key of all entries ordered by timestamp as long as the
+    /// timestamp falls within the given timestamp range.
+    ///
+    /// This is the lightweight counterpart to `scan_range`, reading only the timestamp index.
+    pub fn range_keys<R>(&self, range: R) -> Result<RangeKeys<'a, T>>
+    where
+        R: RangeBounds<T::Timestamp>,
+    {
+        let timestamp = range.start_inclusive().unwrap_or(MinKey::min_key());
+        let end_exclusive = range.end_exclusive();
+        let timestamped_key = Key { timestamp, key: MinKey::min_key() };
+        let iter = self.timestamp_table.scan(&timestamped_key)?;
+        let iter = KeysByTime { iter };
+        Ok(RangeKeys { iter, end_exclusive })
+    }
+
+    /// Collect all entries ordered by the timestamp associated with each as long as it falls
+    /// within the given timestamp range.
+    ///
+    /// This produces the same entries as collecting `scan_range`, however the timestamp index is
+    /// read in full before the values are looked up in ascending order of key, visiting the table
+    /// in a single ordered pass rather than in timestamp order.
+    pub fn collect_range<R>(&self, range: R) -> Result<Vec<(T::Key, T::Value)>>
+    where
+        R: RangeBounds<T::Timestamp>,
+    {
+        let mut index_keys = vec![];
+        for res in self.range_keys(range)? {
+            let (timestamp, key) = res?;
+            index_keys.push(Key { timestamp, key });
+        }
+        self.resolve_many(index_keys)
+    }
+
+    /// Collect all entries whose timestamps fall within `start..=end`, ordered by timestamp.
+    pub fn between(
+        &self,
+        start: T::Timestamp,
+        end: T::Timestamp,
+    ) -> Result<Vec<(T::Key, T::Value)>>
+    where
+        T::Timestamp: Clone,
+    {
+        // The inclusive end is unbounded when it is the maximum possible timestamp.
+        let iter = match end.checked_next() {
+            None => self.scan_range(start..)?,
+            Some(end_exclusive) => self.scan_range(start..end_exclusive)?,
+        };
+        iter.collect()
+    }
+
+    /// Collect up to the `n` most recent entries in descending order of timestamp.
+    ///
+    /// The timestamp index is walked backward from its greatest entry, so only the returned
+    /// entries are read. Fewer than `n` entries are returned if the table holds fewer than `n`.
+    pub fn tail(&self, n: usize) -> Result<Vec<(T::Key, T::Value)>> {
+        self.iter()?.rev().take(n).collect()
+    }
+
+    /// Iterate over all entries in descending order of the timestamp associated with each as long
+    /// as it falls within the given timestamp range.
+    ///
+    /// Iteration begins at the range's upper bound and ends at its lower bound.
+    pub fn scan_range_rev<R>(&self, range: R) -> Result<IterRangeRev<'a, T>>
+    where
+        R: RangeBounds<T::Timestamp>,
+    {
+        self.rev_range(range.start_inclusive(), range.end_exclusive())
+    }
+
+    // Iterate over all entries in descending order of timestamp within the given bounds.
+    fn rev_range(
+        &self,
+        start_inclusive: Option<T::Timestamp>,
+        end_exclusive: Option<T::Timestamp>,
+    ) -> Result<IterRangeRev<'a, T>> {
+        let (iter, end_exclusive) = match end_exclusive {
+            None => (self.timestamp_table.iter_rev()?, None),
+            Some(timestamp) => {
+                let timestamped_key = Key { timestamp, key: MinKey::min_key() };
+                let iter = self.timestamp_table.scan_rev(&timestamped_key)?;
+                (iter, Some(timestamped_key.timestamp))
+            }
+        };
+        let table = self.table.clone().into();
+        Ok(IterRangeRev { iter, table, start_inclusive, end_exclusive })
+    }
+
+    /// Return the chronologically first entry within the table.
+    ///
+    /// If more than one entry shares the earliest timestamp, the entry with the smallest key is
+    /// returned.
+    pub fn earliest(&self) -> Result<Option<(T::Key, T::Value)>> {
+        match self.iter()?.next() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(kv)) => Ok(Some(kv)),
+        }
+    }
+
+    /// Group all entries ordered by timestamp into consecutive windows that span `step` each.
+    ///
+    /// Windows are aligned to multiples of `step`, and each is yielded as its starting timestamp
+    /// along with its entries. Windows that contain no entries are skipped. If the aligned start
+    /// of the earliest window precedes the minimum timestamp, that window starts at the minimum
+    /// timestamp instead.
+    ///
+    /// Returns `Error::ZeroStep` if `step` is zero.
+    pub fn window(&self, step: T::Timestamp) -> Result<WindowIter<'a, T>>
+    where
+        T::Timestamp: Clone
+            + CheckedSub
+            + ops::Sub<Output = T::Timestamp>
+            + ops::Rem<Output = T::Timestamp>,
+    {
+        check_step(&step)?;
+        let iter = self.iter()?;
+        let next = None;
+        Ok(WindowIter { iter, step, next })
+    }
+
     /// Return the entry that is equal to or the successor of the given timestamp.
     ///
     /// Returns `None` if no such entry exists.
@@ -159,7 +548,7 @@ where
 
     /// Return the entry that is the successor of the given timestamp.
     ///
-    /// Returns `None` if no such entry exists.
+    /// Returns `None` if no such entry exists, including when `time` is the maximum timestamp.
     ///
     /// This is similar to using the `scan(time).next()` method, but is non-inclusive of the given
     /// key.
@@ -167,13 +556,39 @@ where
     where
         T::Key: PartialEq,
     {
-        let next_time = time.next();
+        let next_time = match time.checked_next() {
+            None => return Ok(None),
+            Some(next_time) => next_time,
+        };
         match self.scan(next_time)?.next() {
             None => Ok(None),
             Some(Err(err)) => Err(err),
             Some(Ok((_, v))) => Ok(Some(T::value_timestamp(&v))),
         }
     }
+
+    /// Return the entry whose timestamp is equal to or the successor of the given timestamp.
+    ///
+    /// If more than one entry shares the resulting timestamp, the entry with the smallest key is
+    /// returned. Stale timestamp entries are skipped as within `scan`.
+    pub fn succ_incl_entry(&self, timestamp: T::Timestamp) -> Result<Option<(T::Key, T::Value)>> {
+        match self.scan(timestamp)?.next() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(kv)) => Ok(Some(kv)),
+        }
+    }
+
+    /// Return the entry whose timestamp is the successor of the given timestamp.
+    ///
+    /// If more than one entry shares the resulting timestamp, the entry with the smallest key is
+    /// returned. Stale timestamp entries are skipped as within `scan`.
+    pub fn succ_entry(&self, timestamp: T::Timestamp) -> Result<Option<(T::Key, T::Value)>> {
+        match timestamp.checked_next() {
+            None => Ok(None),
+            Some(next_timestamp) => self.succ_incl_entry(next_timestamp),
+        }
+    }
 }
 
 impl<'a, T> Reader<'a, T>
@@ -198,12 +613,51 @@ where
         Ok(self.timestamp_table.pred(&timestamped_key)?.map(|(tk, _)| tk.timestamp))
     }
 
+    /// Find and return the entry whose timestamp is equal to or precedes the given timestamp.
+    ///
+    /// If more than one entry shares the resulting timestamp, the entry with the largest key is
+    /// returned, consistent with `latest`. Stale timestamp entries are skipped as within
+    /// `scan_range_rev`.
+    pub fn pred_incl_entry(&self, timestamp: T::Timestamp) -> Result<Option<(T::Key, T::Value)>> {
+        // The inclusive end is unbounded when it is the maximum possible timestamp.
+        match self.rev_range(None, timestamp.checked_next())?.next() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(kv)) => Ok(Some(kv)),
+        }
+    }
+
+    /// Find and return the entry whose timestamp precedes the given timestamp.
+    ///
+    /// If more than one entry shares the resulting timestamp, the entry with the largest key is
+    /// returned, consistent with `latest`. Stale timestamp entries are skipped as within
+    /// `scan_range_rev`.
+    pub fn pred_entry(&self, timestamp: T::Timestamp) -> Result<Option<(T::Key, T::Value)>> {
+        match self.rev_range(None, Some(timestamp))?.next() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(kv)) => Ok(Some(kv)),
+        }
+    }
+
     /// Find and return the maximum entry within the table.
     ///
     /// This produces the same result as `iter().last()` but much more efficiently.
     pub fn max(&self) -> Result<Option<T::Timestamp>> {
         Ok(self.timestamp_table.max()?.map(|(tk, _)| tk.timestamp))
     }
+
+    /// Return the chronologically last entry within the table.
+    ///
+    /// If more than one entry shares the latest timestamp, the entry with the largest key is
+    /// returned, i.e. the last entry yielded by `iter`, consistent with `max`.
+    pub fn latest(&self) -> Result<Option<(T::Key, T::Value)>> {
+        match self.iter()?.next_back() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(kv)) => Ok(Some(kv)),
+        }
+    }
 }
 
 // Writer implementations.
@@ -214,15 +668,210 @@ where
     T::Key: Clone,
 {
     /// Set the given **key** to the new **value** with the given **timestamp**.
+    ///
+    /// If the key already exists with a different timestamp, its stale timestamp entry is removed.
+    ///
+    /// The new timestamp entry is written before the value and the old value is replaced
+    /// atomically, so the timestamp index never lacks an entry for a value within the table.
+    ///
+    /// **Note:** The index entries and the value are not written atomically as a whole. If the
+    /// process stops before the stale timestamp entry is removed, it remains within the index and
+    /// is skipped by the methods that resolve values, as described on **Timestamped**.
     pub fn set(&self, key: &T::Key, value: &T::Value) -> Result<()> {
         let timestamp = T::value_timestamp(value);
         let timestamped_key = Key { timestamp, key: key.clone() };
-        self.timestamp_table.del(&timestamped_key)?;
-        self.table.set(key, value)?;
         self.timestamp_table.set(&timestamped_key, &())?;
+        if let Some(old_value) = self.table.swap(key, value)? {
+            let old_timestamp = T::value_timestamp(&old_value);
+            if old_timestamp != timestamped_key.timestamp {
+                let old_timestamped_key = Key { timestamp: old_timestamp, key: key.clone() };
+                self.timestamp_table.remove(&old_timestamped_key)?;
+            }
+        }
         Ok(())
     }
 
+    /// Set the given **key** to the new **value** while preserving the timestamp of the existing
+    /// entry, leaving its chronological position unchanged.
+    ///
+    /// The timestamp of the given **value** is ignored and replaced by that of the existing entry
+    /// before it is written, so only the value within the table is rewritten. If no entry exists
+    /// for the key, this behaves like `set`.
+    pub fn set_preserving_timestamp(&self, key: &T::Key, value: &T::Value) -> Result<()>
+    where
+        T: SetTimestamp,
+        T::Value: Clone,
+    {
+        let key_bytes = self.table.key_bytes(key)?;
+        self.table.with_retries(::DEFAULT_MAX_RETRIES, || {
+            let tree = self.table.tree;
+            let old_bytes = match tree.get(&key_bytes)? {
+                None => return self.set(key, value).map(Some),
+                Some(old_bytes) => old_bytes,
+            };
+            let old_timestamp = T::value_timestamp(&::read_value::<T>(&old_bytes)?);
+            let mut new_value = value.clone();
+            T::set_value_timestamp(&mut new_value, old_timestamp);
+            let new_bytes = ::write_value::<T>(&new_value)?;
+            match Store::cas(tree, key_bytes.clone(), Some(old_bytes), Some(new_bytes))? {
+                Ok(()) => Ok(Some(())),
+                Err(_) => Ok(None),
+            }
+        })
+    }
+
+    /// Replace the timestamp of the entry for the given **key**, leaving the rest of its value
+    /// unchanged.
+    ///
+    /// Returns whether or not an entry existed for the key. As with `set`, the new timestamp entry
+    /// is written before the value is replaced via compare-and-swap, after which the stale
+    /// timestamp entry is removed.
+    pub fn touch(&self, key: &T::Key, timestamp: T::Timestamp) -> Result<bool>
+    where
+        T: SetTimestamp,
+        T::Timestamp: Clone,
+    {
+        let key_bytes = self.table.key_bytes(key)?;
+        self.table.with_retries(::DEFAULT_MAX_RETRIES, || {
+            let tree = self.table.tree;
+            let old_bytes = match tree.get(&key_bytes)? {
+                None => return Ok(Some(false)),
+                Some(old_bytes) => old_bytes,
+            };
+            let mut value = ::read_value::<T>(&old_bytes)?;
+            let old_timestamp = T::value_timestamp(&value);
+            T::set_value_timestamp(&mut value, timestamp.clone());
+            let new_bytes = ::write_value::<T>(&value)?;
+            let changed = old_timestamp != timestamp;
+            let timestamped_key = Key { timestamp: timestamp.clone(), key: key.clone() };
+            if changed {
+                self.timestamp_table.set(&timestamped_key, &())?;
+            }
+            match Store::cas(tree, key_bytes.clone(), Some(old_bytes), Some(new_bytes))? {
+                Ok(()) => {
+                    if changed {
+                        let old_key = Key { timestamp: old_timestamp, key: key.clone() };
+                        self.timestamp_table.remove(&old_key)?;
+                    }
+                    Ok(Some(true))
+                }
+                Err(current_bytes) => {
+                    if changed {
+                        self.remove_unless_current(&timestamped_key, current_bytes)?;
+                    }
+                    Ok(None)
+                }
+            }
+        })
+    }
+
+    /// Move the value for the `from` key to the `to` key, replacing any existing value for `to`
+    /// and updating the timestamp index to match.
+    ///
+    /// Returns whether or not an entry existed for `from`.
+    ///
+    /// As with the plain `Writer::move_key`, the value is moved via a pair of compare-and-swaps.
+    /// The timestamp entry for `to` is written before the value is moved and the stale timestamp
+    /// entries are only removed afterwards, so the move itself never leaves a value without a
+    /// timestamp entry.
+    ///
+    /// **Note:** If either key is concurrently re-set with the same timestamp as its stale entry
+    /// before that entry is removed, the timestamp entry written by that set is removed, leaving
+    /// its value without a timestamp entry until the key is next set.
+    pub fn rename_key(&self, from: &T::Key, to: &T::Key) -> Result<bool> {
+        let from_bytes = self.table.key_bytes(from)?;
+        let to_bytes = self.table.key_bytes(to)?;
+        let tree = self.table.tree;
+        if from_bytes == to_bytes {
+            return Ok(tree.get(&from_bytes)?.is_some());
+        }
+        self.table.with_retries(::DEFAULT_MAX_RETRIES, || {
+            let value_bytes = match tree.get(&from_bytes)? {
+                None => return Ok(Some(false)),
+                Some(value_bytes) => value_bytes,
+            };
+            let timestamp = T::value_timestamp(&::read_value::<T>(&value_bytes)?);
+            let prev_bytes = tree.get(&to_bytes)?;
+            let new_bytes = Some(value_bytes.clone());
+            let to_key = Key { timestamp, key: to.clone() };
+            self.timestamp_table.set(&to_key, &())?;
+            let cas = Store::cas(tree, to_bytes.clone(), prev_bytes.clone(), new_bytes.clone())?;
+            if let Err(current_bytes) = cas {
+                self.remove_unless_current(&to_key, current_bytes)?;
+                return Ok(None);
+            }
+            if Store::cas(tree, from_bytes.clone(), Some(value_bytes), None)?.is_err() {
+                // Only restore `to` if no other writer has replaced it since.
+                let restore = Store::cas(tree, to_bytes.clone(), new_bytes, prev_bytes.clone())?;
+                let current_bytes = match restore {
+                    Ok(()) => prev_bytes,
+                    Err(current_bytes) => current_bytes,
+                };
+                self.remove_unless_current(&to_key, current_bytes)?;
+                return Ok(None);
+            }
+            let Key { timestamp, .. } = to_key;
+            if let Some(prev_bytes) = prev_bytes {
+                let prev_timestamp = T::value_timestamp(&::read_value::<T>(&prev_bytes)?);
+                if prev_timestamp != timestamp {
+                    let prev_key = Key { timestamp: prev_timestamp, key: to.clone() };
+                    self.timestamp_table.remove(&prev_key)?;
+                }
+            }
+            self.timestamp_table.remove(&Key { timestamp, key: from.clone() })?;
+            Ok(Some(true))
+        })
+    }
+
+    /// Remove every entry whose timestamp is strictly less than `cutoff`, along with its
+    /// timestamp entry.
+    ///
+    /// Returns the number of entries removed. Each value is removed via compare-and-swap, so an
+    /// entry that is concurrently re-set with a newer timestamp is kept.
+    ///
+    /// **Note:** Each timestamp entry is removed after its value. If the same key is concurrently
+    /// re-set with the *same* timestamp in between, the timestamp entry written by that set is
+    /// removed, leaving its value without a timestamp entry until the key is next set.
+    pub fn gc_older_than(&self, cutoff: T::Timestamp) -> Result<usize> {
+        let mut count = 0;
+        for res in self.iter_keys_by_time()? {
+            let (timestamp, key) = res?;
+            if timestamp >= cutoff {
+                break;
+            }
+            // Skip index entries that are not reflected by the table, as within `Iter`.
+            let value = match self.table.get(&key)? {
+                None => continue,
+                Some(value) => value,
+            };
+            if T::value_timestamp(&value) != timestamp {
+                continue;
+            }
+            if self.table.compare_and_delete(&key, &value)? {
+                self.timestamp_table.remove(&Key { timestamp, key })?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Remove every entry whose age at `now` exceeds `T::TTL`, along with its timestamp entry.
+    ///
+    /// Returns the number of entries removed. This is equivalent to `gc_older_than(now - T::TTL)`,
+    /// so an entry whose age is exactly `T::TTL` is kept. If `now - T::TTL` cannot be represented,
+    /// e.g. when `now` precedes `T::TTL` for an unsigned timestamp, no entry may have expired and
+    /// nothing is removed.
+    pub fn expire(&self, now: T::Timestamp) -> Result<usize>
+    where
+        T: Ttl,
+        T::Timestamp: CheckedSub,
+    {
+        match now.checked_sub(&T::TTL) {
+            None => Ok(0),
+            Some(cutoff) => self.gc_older_than(cutoff),
+        }
+    }
+
     /// Remove a value from the **Tree** if it exists along with its timestamp entry.
     pub fn del(&self, key: &T::Key) -> Result<Option<T::Value>> {
         if let Some(value) = self.table.del(key)? {
@@ -234,6 +883,101 @@ where
             Ok(None)
         }
     }
+
+    // Remove the given timestamp entry after a failed compare-and-swap, unless the current value
+    // bytes for its key carry the same timestamp and in turn depend on the entry.
+    fn remove_unless_current(
+        &self,
+        timestamped_key: &Key<T::Timestamp, T::Key>,
+        current_bytes: Option<Vec<u8>>,
+    ) -> Result<()> {
+        if let Some(bytes) = current_bytes {
+            if T::value_timestamp(&::read_value::<T>(&bytes)?) == timestamped_key.timestamp {
+                return Ok(());
+            }
+        }
+        self.timestamp_table.remove(timestamped_key)?;
+        Ok(())
+    }
+}
+
+// Explicitly timestamped table implementations.
+
+impl<'a, T, S> ExplicitReader<'a, T, S>
+where
+    T: TimestampedExplicit,
+    S: Store,
+{
+    /// Retrieve a value from the **Tree** if it exists.
+    pub fn get(&self, key: &T::Key) -> Result<Option<T::Value>> {
+        self.table.get(key)
+    }
+
+    /// Retrieve the timestamp of the entry for the given **key** if it exists.
+    pub fn timestamp(&self, key: &T::Key) -> Result<Option<T::Timestamp>> {
+        self.key_timestamp_table.get(key)
+    }
+
+    /// Iterate over all entries ordered by the timestamp supplied for each.
+    pub fn iter(&self) -> Result<ExplicitIter<'a, T, S>>
+    where
+        T::Key: MinKey,
+    {
+        self.scan(MinKey::min_key())
+    }
+
+    /// Iterate over all entries ordered by the timestamp supplied for each, starting from the
+    /// given **timestamp**.
+    pub fn scan(&self, timestamp: T::Timestamp) -> Result<ExplicitIter<'a, T, S>>
+    where
+        T::Key: MinKey,
+    {
+        let timestamped_key = Key { timestamp, key: MinKey::min_key() };
+        let iter = self.timestamp_table.scan(&timestamped_key)?;
+        let table = self.table.clone().into();
+        let key_timestamp_table = self.key_timestamp_table.clone().into();
+        Ok(ExplicitIter { iter, table, key_timestamp_table })
+    }
+}
+
+impl<'a, T, S> ExplicitWriter<'a, T, S>
+where
+    T: TimestampedExplicit,
+    T::Key: Clone,
+    S: Store,
+{
+    /// Set the given **key** to the new **value** with the explicitly supplied **timestamp**.
+    ///
+    /// If the key already exists with a different timestamp, its stale timestamp entry is removed.
+    ///
+    /// The timestamp entry is written before the value, and the timestamp of the key is only
+    /// replaced afterwards, so the timestamp of a key is always indexed.
+    pub fn set(&self, key: &T::Key, timestamp: T::Timestamp, value: &T::Value) -> Result<()> {
+        let timestamped_key = Key { timestamp, key: key.clone() };
+        self.timestamp_table.set(&timestamped_key, &())?;
+        self.table.set(key, value)?;
+        let Key { timestamp, .. } = timestamped_key;
+        if let Some(old_timestamp) = self.key_timestamp_table.swap(key, &timestamp)? {
+            if old_timestamp != timestamp {
+                let old_timestamped_key = Key { timestamp: old_timestamp, key: key.clone() };
+                self.timestamp_table.remove(&old_timestamped_key)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the entry for the given **key** if it exists, returning its value.
+    ///
+    /// The timestamp of the key is removed first, so its timestamp entry is skipped by iterators
+    /// while the value and the timestamp entry are removed.
+    pub fn del(&self, key: &T::Key) -> Result<Option<T::Value>> {
+        let timestamp = self.key_timestamp_table.del(key)?;
+        let value = self.table.del(key)?;
+        if let Some(timestamp) = timestamp {
+            self.timestamp_table.remove(&Key { timestamp, key: key.clone() })?;
+        }
+        Ok(value)
+    }
 }
 
 // Trait implementations.
@@ -321,27 +1065,200 @@ where
     }
 }
 
+impl<'a, T, S> From<&'a S> for ExplicitReader<'a, T, S>
+where
+    T: TimestampedExplicit,
+    S: Store,
+{
+    fn from(tree: &'a S) -> Self {
+        let table = tree.into();
+        let timestamp_table = tree.into();
+        let key_timestamp_table = tree.into();
+        ExplicitReader { table, timestamp_table, key_timestamp_table }
+    }
+}
+
+impl<'a, T, S> From<&'a S> for ExplicitWriter<'a, T, S>
+where
+    T: TimestampedExplicit,
+    S: Store,
+{
+    fn from(tree: &'a S) -> Self {
+        let reader: ExplicitReader<'a, T, S> = tree.into();
+        ExplicitWriter { reader }
+    }
+}
+
+impl<'a, T, S> From<ExplicitWriter<'a, T, S>> for ExplicitReader<'a, T, S>
+where
+    T: TimestampedExplicit,
+{
+    fn from(w: ExplicitWriter<'a, T, S>) -> Self {
+        w.reader
+    }
+}
+
+impl<'a, T, S> Clone for ExplicitReader<'a, T, S>
+where
+    T: TimestampedExplicit,
+{
+    fn clone(&self) -> Self {
+        let table = self.table.clone();
+        let timestamp_table = self.timestamp_table.clone();
+        let key_timestamp_table = self.key_timestamp_table.clone();
+        ExplicitReader { table, timestamp_table, key_timestamp_table }
+    }
+}
+
+impl<'a, T, S> Clone for ExplicitWriter<'a, T, S>
+where
+    T: TimestampedExplicit,
+{
+    fn clone(&self) -> Self {
+        let reader = self.reader.clone();
+        ExplicitWriter { reader }
+    }
+}
+
+impl<'a, T, S> ops::Deref for ExplicitWriter<'a, T, S>
+where
+    T: TimestampedExplicit,
+{
+    type Target = ExplicitReader<'a, T, S>;
+    fn deref(&self) -> &Self::Target {
+        &self.reader
+    }
+}
+
+impl<'a, T> Iter<'a, T>
+where
+    T: Timestamped,
+{
+    // Look up the entry for the given index key, returning `None` if it should be skipped.
+    fn resolve(&self, tk: Key<T::Timestamp, T::Key>) -> Option<Result<(T::Key, T::Value)>> {
+        let Key { timestamp, key } = tk;
+        // The index may briefly contain entries that are not yet (or no longer) reflected by the
+        // table while a write is in progress, so skip any that don't match.
+        match self.table.get(&key) {
+            Err(err) => Some(Err(err)),
+            Ok(None) => None,
+            Ok(Some(value)) => {
+                if timestamp != T::value_timestamp(&value) {
+                    return None;
+                }
+                Some(Ok((key, value)))
+            }
+        }
+    }
+}
+
 impl<'a, T> Iterator for Iter<'a, T>
 where
     T: Timestamped,
 {
     type Item = Result<(T::Key, T::Value)>;
     fn next(&mut self) -> Option<Self::Item> {
-        let Key { timestamp, key } = match self.iter.next() {
-            None => return None,
-            Some(Err(err)) => return Some(Err(err)),
-            Some(Ok((tk, ()))) => tk,
-        };
-        let value = match self.table.get(&key) {
-            Err(err) => return Some(Err(err)),
-            Ok(None) => panic!("timestamp user key invalid - no value found"),
-            Ok(Some(value)) => value,
-        };
-        // Sanity check.
-        if timestamp != T::value_timestamp(&value) {
-            panic!("timestamp key does not match value's");
+        loop {
+            let timestamped_key = match self.iter.next()? {
+                Err(err) => return Some(Err(err)),
+                Ok((tk, ())) => tk,
+            };
+            // Stop once we reach an entry that has already been read from the back.
+            if let Some(ref back_cursor) = self.back_cursor {
+                if self.iter.cursor().map_or(false, |k| k >= &back_cursor[..]) {
+                    return None;
+                }
+            }
+            if let Some(res) = self.resolve(timestamped_key) {
+                return Some(res);
+            }
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T>
+where
+    T: Timestamped,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rev.is_none() {
+            let reader = ::Reader::<T::TimestampTable, _>::new(self.table.tree);
+            match reader.iter_rev_bytes() {
+                Err(err) => return Some(Err(err)),
+                Ok(rev) => self.rev = Some(rev),
+            }
+        }
+        loop {
+            let (key_bytes, value_bytes, id_len) = {
+                let rev = self.rev.as_mut().expect("no reverse iterator");
+                let (key_bytes, value_bytes) = match rev.next()? {
+                    Err(err) => return Some(Err(err)),
+                    Ok(kv) => kv,
+                };
+                // Stop once we pass the start or reach an entry that has already been read from
+                // the front.
+                let read_from_front = match self.iter.cursor() {
+                    None => false,
+                    Some(front_cursor) => &key_bytes[..] <= front_cursor,
+                };
+                if key_bytes < self.start_bytes || read_from_front {
+                    rev.key_bytes = None;
+                    return None;
+                }
+                (key_bytes, value_bytes, rev.id_bytes.len())
+            };
+            let entry = ::read_entry::<T::TimestampTable>(&key_bytes[id_len..], &value_bytes);
+            let timestamped_key = match entry {
+                Err(err) => return Some(Err(err)),
+                Ok((tk, ())) => tk,
+            };
+            self.back_cursor = Some(key_bytes);
+            if let Some(res) = self.resolve(timestamped_key) {
+                return Some(res);
+            }
+        }
+    }
+}
+
+impl<'a, T, S> Iterator for ExplicitIter<'a, T, S>
+where
+    T: TimestampedExplicit,
+    S: Store,
+{
+    type Item = Result<(Key<T::Timestamp, T::Key>, T::Value)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let timestamped_key = match self.iter.next()? {
+                Err(err) => return Some(Err(err)),
+                Ok((tk, ())) => tk,
+            };
+            // The index may briefly contain entries that are not yet (or no longer) reflected by
+            // the timestamp of their key while a write is in progress, so skip any that don't
+            // match.
+            match self.key_timestamp_table.get(&timestamped_key.key) {
+                Err(err) => return Some(Err(err)),
+                Ok(Some(ref timestamp)) if *timestamp == timestamped_key.timestamp => (),
+                Ok(_) => continue,
+            }
+            match self.table.get(&timestamped_key.key) {
+                Err(err) => return Some(Err(err)),
+                Ok(None) => continue,
+                Ok(Some(value)) => return Some(Ok((timestamped_key, value))),
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for KeysByTime<'a, T>
+where
+    T: Timestamped,
+{
+    type Item = Result<(T::Timestamp, T::Key)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            Err(err) => Some(Err(err)),
+            Ok((Key { timestamp, key }, ())) => Some(Ok((timestamp, key))),
         }
-        Some(Ok((key, value)))
     }
 }
 
@@ -363,6 +1280,121 @@ where
     }
 }
 
+impl<'a, T> Iterator for RangeKeys<'a, T>
+where
+    T: Timestamped,
+{
+    type Item = Result<(T::Timestamp, T::Key)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (timestamp, key) = match self.iter.next()? {
+            Err(err) => return Some(Err(err)),
+            Ok(tk) => tk,
+        };
+        match self.end_exclusive {
+            Some(ref end_exclusive) if *end_exclusive <= timestamp => None,
+            _ => Some(Ok((timestamp, key))),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterRangeRev<'a, T>
+where
+    T: Timestamped,
+{
+    type Item = Result<(T::Key, T::Value)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Key { timestamp, key } = match self.iter.next()? {
+                Err(err) => return Some(Err(err)),
+                Ok((tk, ())) => tk,
+            };
+            match self.end_exclusive {
+                Some(ref end_exclusive) if *end_exclusive <= timestamp => continue,
+                _ => (),
+            }
+            match self.start_inclusive {
+                Some(ref start_inclusive) if timestamp < *start_inclusive => return None,
+                _ => (),
+            }
+            // Skip index entries that are not reflected by the table, as within `Iter`.
+            match self.table.get(&key) {
+                Err(err) => return Some(Err(err)),
+                Ok(None) => continue,
+                Ok(Some(value)) => {
+                    if timestamp != T::value_timestamp(&value) {
+                        continue;
+                    }
+                    return Some(Ok((key, value)));
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for WindowIter<'a, T>
+where
+    T: Timestamped,
+    T::Timestamp: Clone
+        + CheckedSub
+        + ops::Sub<Output = T::Timestamp>
+        + ops::Rem<Output = T::Timestamp>,
+{
+    type Item = Result<(T::Timestamp, Vec<(T::Key, T::Value)>)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, first) = match self.next.take() {
+            Some(next) => next,
+            None => match self.iter.next()? {
+                Err(err) => return Some(Err(err)),
+                Ok(kv) => (window_start(T::value_timestamp(&kv.1), &self.step), kv),
+            },
+        };
+        let mut entries = vec![first];
+        loop {
+            let kv = match self.iter.next() {
+                None => break,
+                Some(Err(err)) => return Some(Err(err)),
+                Some(Ok(kv)) => kv,
+            };
+            let kv_start = window_start(T::value_timestamp(&kv.1), &self.step);
+            if kv_start != start {
+                self.next = Some((kv_start, kv));
+                break;
+            }
+            entries.push(kv);
+        }
+        Some(Ok((start, entries)))
+    }
+}
+
+// Produces an error if the given window `step` is zero, as no timestamp could be assigned a window.
+fn check_step<T>(step: &T) -> Result<()>
+where
+    T: Clone + PartialEq + ops::Sub<Output = T>,
+{
+    let zero = step.clone() - step.clone();
+    match *step == zero {
+        true => Err(::Error::ZeroStep),
+        false => Ok(()),
+    }
+}
+
+// The start of the window spanning `step` that contains the given timestamp.
+//
+// Windows that would start before the minimum timestamp start at the minimum timestamp instead.
+fn window_start<T>(timestamp: T, step: &T) -> T
+where
+    T: Timestamp + Clone + CheckedSub + ops::Sub<Output = T> + ops::Rem<Output = T>,
+{
+    let rem = timestamp.clone() % step.clone();
+    let start = timestamp.clone() - rem;
+    // Negative timestamps may be truncated towards zero, beyond the timestamp itself.
+    if start > timestamp {
+        start.checked_sub(step).unwrap_or_else(MinKey::min_key)
+    } else {
+        start
+    }
+}
+
 // `RangeBounds` implementations - to be removed once `std::ops::RangeBounds` stabilises.
 
 impl<T> RangeBounds<T> for ops::Range<T>
@@ -471,3 +1503,63 @@ impl MinKey for isize {
         std::isize::MIN
     }
 }
+
+impl CheckedSub for u8 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        u8::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for u16 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        u16::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for u32 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        u32::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for u64 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        u64::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for usize {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        usize::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for i8 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        i8::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for i16 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        i16::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for i32 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        i32::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for i64 {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        i64::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedSub for isize {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        isize::checked_sub(*self, *rhs)
+    }
+}
@@ -1,7 +1,8 @@
 //! Items related to performing a binary search over an unsigned integer range.
 
-use {Reader, Result, Table};
-use std::{self, ops};
+use bytekey;
+use {Reader, Result, Store, Table};
+use std::{self, cmp, num, ops};
 
 /// A trait implemented for unsigned integer types that may be used in binary search.
 pub trait UnsignedInteger: Sized
@@ -93,16 +94,95 @@ impl UnsignedBinarySearchKey for usize {
     }
 }
 
+impl UnsignedBinarySearchKey for bool {
+    type UnsignedInteger = u8;
+    fn from_unsigned_integer(u: Self::UnsignedInteger) -> Self {
+        u != 0
+    }
+}
+
+impl UnsignedBinarySearchKey for char {
+    type UnsignedInteger = u32;
+    // Integers that are not valid scalar values are mapped to the next greatest valid `char`, or
+    // to `char::MAX` if there is none, keeping the mapping ordered.
+    fn from_unsigned_integer(u: Self::UnsignedInteger) -> Self {
+        const SURROGATES_END: u32 = 0xE000;
+        match std::char::from_u32(u) {
+            Some(c) => c,
+            None if u < SURROGATES_END => std::char::from_u32(SURROGATES_END).unwrap(),
+            None => std::char::MAX,
+        }
+    }
+}
+
+// The `NonZero*` types map onto their underlying integer type. As zero is not a valid key, it is
+// clamped to one, so the final probe for the minimum key of a search looks up a key of one. Zero
+// and one therefore map to the same key, and `pred(1)` always returns `None` as no lesser key may
+// exist.
+impl UnsignedBinarySearchKey for num::NonZeroU8 {
+    type UnsignedInteger = u8;
+    fn from_unsigned_integer(u: Self::UnsignedInteger) -> Self {
+        num::NonZeroU8::new(cmp::max(u, 1)).expect("`u` is non-zero")
+    }
+}
+
+impl UnsignedBinarySearchKey for num::NonZeroU16 {
+    type UnsignedInteger = u16;
+    fn from_unsigned_integer(u: Self::UnsignedInteger) -> Self {
+        num::NonZeroU16::new(cmp::max(u, 1)).expect("`u` is non-zero")
+    }
+}
+
+impl UnsignedBinarySearchKey for num::NonZeroU32 {
+    type UnsignedInteger = u32;
+    fn from_unsigned_integer(u: Self::UnsignedInteger) -> Self {
+        num::NonZeroU32::new(cmp::max(u, 1)).expect("`u` is non-zero")
+    }
+}
+
+impl UnsignedBinarySearchKey for num::NonZeroU64 {
+    type UnsignedInteger = u64;
+    fn from_unsigned_integer(u: Self::UnsignedInteger) -> Self {
+        num::NonZeroU64::new(cmp::max(u, 1)).expect("`u` is non-zero")
+    }
+}
+
+impl UnsignedBinarySearchKey for num::NonZeroUsize {
+    type UnsignedInteger = usize;
+    fn from_unsigned_integer(u: Self::UnsignedInteger) -> Self {
+        num::NonZeroUsize::new(cmp::max(u, 1)).expect("`u` is non-zero")
+    }
+}
+
 /// Find the maximum entry that precedes the given key within the given `Table` using a binary
 /// search over the key's associated unsigned integer range.
-pub fn find_pred<T>(
-    table: &Reader<T>,
+pub fn find_pred<T, S>(
+    table: &Reader<T, S>,
     key: &T::Key,
     inclusive: bool,
 ) -> Result<Option<(T::Key, T::Value)>>
 where
     T: Table,
     T::Key: UnsignedBinarySearchKey,
+    S: Store,
+{
+    find_pred_by(table, |k| match if inclusive { *k <= *key } else { *k < *key } {
+        true => cmp::Ordering::Less,
+        false => cmp::Ordering::Greater,
+    })
+}
+
+/// Find the maximum entry whose key is ordered before or equal to some target according to the
+/// given comparator using a binary search over the key's associated unsigned integer range.
+///
+/// The comparator `f` is only called with keys that exist within the table. It should return
+/// `Less` or `Equal` for all keys up to the target and `Greater` for all keys after it.
+pub fn find_pred_by<T, S, F>(table: &Reader<T, S>, mut f: F) -> Result<Option<(T::Key, T::Value)>>
+where
+    T: Table,
+    T::Key: UnsignedBinarySearchKey,
+    S: Store,
+    F: FnMut(&T::Key) -> cmp::Ordering,
 {
     let max = <<T::Key as UnsignedBinarySearchKey>::UnsignedInteger as UnsignedInteger>::MAX;
     let one = <<T::Key as UnsignedBinarySearchKey>::UnsignedInteger as UnsignedInteger>::ONE;
@@ -118,7 +198,7 @@ where
             // If there's an error, return it.
             Some(Err(err)) => return Err(err),
             // If it's greater than or equal to the attempt, assign and search upwards.
-            Some(Ok((k, v))) => match if inclusive { k <= *key } else { k < *key } {
+            Some(Ok((k, v))) => match f(&k) != cmp::Ordering::Greater {
                 true => {
                     greatest = Some((k, v));
                     attempt = attempt + step;
@@ -131,8 +211,10 @@ where
     }
     if greatest.is_none() {
         let k = UnsignedBinarySearchKey::from_unsigned_integer(zero);
-        if let Some(v) = table.get(&k)? {
-            greatest = Some((k, v));
+        if f(&k) != cmp::Ordering::Greater {
+            if let Some(v) = table.get(&k)? {
+                greatest = Some((k, v));
+            }
         }
     }
     Ok(greatest)
@@ -140,10 +222,46 @@ where
 
 /// Find the maximum entry within the given `Table` using a binary search over the key's associated
 /// unsigned integer range.
-pub fn find_max<T>(table: &Reader<T>) -> Result<Option<(T::Key, T::Value)>>
+pub fn find_max<T, S>(table: &Reader<T, S>) -> Result<Option<(T::Key, T::Value)>>
+where
+    T: Table,
+    T::Key: UnsignedBinarySearchKey,
+    S: Store,
+{
+    let id_len = table.id_bytes().len();
+    match find_max_raw(table)? {
+        None => Ok(None),
+        Some((k, v)) => Ok(Some(::read_entry::<T>(&k[id_len..], &v)?)),
+    }
+}
+
+/// Find the maximum key within the given `Table` using a binary search over the key's associated
+/// unsigned integer range.
+///
+/// This performs the same search as `find_max`, however no values are deserialized.
+pub fn find_max_key<T, S>(table: &Reader<T, S>) -> Result<Option<T::Key>>
+where
+    T: Table,
+    T::Key: UnsignedBinarySearchKey,
+    S: Store,
+{
+    let id_len = table.id_bytes().len();
+    match find_max_raw(table)? {
+        None => Ok(None),
+        Some((k, _)) => Ok(Some(bytekey::deserialize(&k[id_len..])?)),
+    }
+}
+
+/// Find the raw key and value bytes of the maximum entry within the given `Table` using a binary
+/// search over the key's associated unsigned integer range.
+///
+/// Neither keys nor values are deserialized, and the key bytes include the table's `ID` prefix.
+/// Both `find_max` and `find_max_key` decode the result of this search.
+pub fn find_max_raw<T, S>(table: &Reader<T, S>) -> Result<Option<(Vec<u8>, Vec<u8>)>>
 where
     T: Table,
     T::Key: UnsignedBinarySearchKey,
+    S: Store,
 {
     let max = <<T::Key as UnsignedBinarySearchKey>::UnsignedInteger as UnsignedInteger>::MAX;
     let one = <<T::Key as UnsignedBinarySearchKey>::UnsignedInteger as UnsignedInteger>::ONE;
@@ -155,12 +273,12 @@ where
     while step != zero {
         step = step / two;
         let attempt_key: T::Key = UnsignedBinarySearchKey::from_unsigned_integer(attempt);
-        match table.scan(&attempt_key)?.next() {
+        match table.scan_bytes(&attempt_key)?.next() {
             // If there's an error, return it.
             Some(Err(err)) => return Err(err),
             // If it's greater than or equal to the attempt, assign and search upwards.
-            Some(Ok((k, v))) => {
-                greatest = Some((k, v));
+            Some(Ok(kv)) => {
+                greatest = Some(kv);
                 attempt = attempt + step;
             },
             // If there's no keys, search downwards.
@@ -168,9 +286,10 @@ where
         }
     }
     if greatest.is_none() {
-        let k = UnsignedBinarySearchKey::from_unsigned_integer(zero);
-        if let Some(v) = table.get(&k)? {
-            greatest = Some((k, v));
+        let k: T::Key = UnsignedBinarySearchKey::from_unsigned_integer(zero);
+        let key_bytes = table.key_bytes(&k)?;
+        if let Some(value_bytes) = table.tree.get(&key_bytes)? {
+            greatest = Some((key_bytes, value_bytes));
         }
     }
     Ok(greatest)
@@ -0,0 +1,47 @@
+#![cfg(feature = "checksum")]
+
+extern crate sled;
+extern crate sled_table;
+
+use sled_table::{Error, Table};
+
+// A table whose values are written with a trailing checksum.
+struct ChecksumTable;
+
+impl Table for ChecksumTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = Vec<u8>;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 0;
+    const CHECKSUM: bool = true;
+}
+
+#[test]
+fn test_checksum_table() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ChecksumTable>::from(&tree);
+
+    // Round-trip.
+    let value = vec![1, 2, 3, 4];
+    table.set(&0, &value).unwrap();
+    assert_eq!(table.get(&0).unwrap().unwrap(), value);
+    assert_eq!(table.iter().unwrap().next().unwrap().unwrap(), (0, value.clone()));
+
+    // Flipping a single bit of the stored value is detected.
+    let mut bytes = table.get_raw(&0).unwrap().unwrap();
+    bytes[2] ^= 0b0001_0000;
+    table.set_raw(&0, &bytes).unwrap();
+    match table.get(&0) {
+        Err(Error::ChecksumMismatch) => (),
+        res => panic!("expected a checksum mismatch, found {:?}", res),
+    }
+
+    // As are values too short to contain a checksum.
+    table.set_raw(&0, &[0, 1]).unwrap();
+    match table.get(&0) {
+        Err(Error::ChecksumMismatch) => (),
+        res => panic!("expected a checksum mismatch, found {:?}", res),
+    }
+}
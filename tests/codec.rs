@@ -0,0 +1,85 @@
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate sled;
+extern crate sled_table;
+
+use sled_table::{Table, ValueCodec};
+
+// A codec encoding values as JSON.
+struct Json;
+
+impl ValueCodec for Json {
+    fn encode<T>(value: &T::Value) -> sled_table::Result<Vec<u8>>
+    where
+        T: ?Sized + Table,
+    {
+        serde_json::to_vec(value).map_err(|err| sled_table::Error::Codec(Box::new(err)))
+    }
+
+    fn decode<T>(bytes: &[u8]) -> sled_table::Result<T::Value>
+    where
+        T: ?Sized + Table,
+    {
+        serde_json::from_slice(bytes).map_err(|err| sled_table::Error::Codec(Box::new(err)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+// A table whose values are encoded as JSON.
+struct JsonTable;
+
+// A table whose values are encoded via the default `bincode` codec.
+struct BincodeTable;
+
+impl Table for JsonTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = Point;
+    type Codec = Json;
+    const ID: Self::Id = 0;
+}
+
+impl Table for BincodeTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = Point;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 1;
+}
+
+#[test]
+fn test_per_table_codec() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let json = sled_table::Writer::<JsonTable>::from(&tree);
+    let bincode = sled_table::Writer::<BincodeTable>::from(&tree);
+    let a = Point { x: 1, y: -2 };
+    let b = Point { x: 3, y: 4 };
+    json.set(&0, &a).unwrap();
+    bincode.set(&0, &b).unwrap();
+
+    // Each table's values are stored via its own codec.
+    assert_eq!(json.get_raw(&0).unwrap().unwrap(), br#"{"x":1,"y":-2}"#.to_vec());
+    let b_bytes = sled_table::Bincode::encode::<BincodeTable>(&b).unwrap();
+    assert_eq!(bincode.get_raw(&0).unwrap().unwrap(), b_bytes);
+
+    // Neither table interferes with the other.
+    assert_eq!(json.get(&0).unwrap(), Some(a.clone()));
+    assert_eq!(bincode.get(&0).unwrap(), Some(b.clone()));
+    assert_eq!(json.iter().unwrap().map(Result::unwrap).collect::<Vec<_>>(), vec![(0, a)]);
+    assert_eq!(bincode.iter().unwrap().map(Result::unwrap).collect::<Vec<_>>(), vec![(0, b)]);
+
+    // Bytes that are invalid for the table's codec produce a codec error.
+    json.set_raw(&1, &[0, 1, 2]).unwrap();
+    match json.get(&1) {
+        Err(sled_table::Error::Codec(_)) => (),
+        other => panic!("expected a codec error, found {:?}", other),
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "compress")]
+
+extern crate sled;
+extern crate sled_table;
+
+use sled_table::Table;
+
+// A table whose values are compressed before being written to the tree.
+struct CompressedTable;
+
+// An identical table whose values are written as-is.
+struct PlainTable;
+
+impl Table for CompressedTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = Vec<u8>;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 0;
+    const COMPRESS: bool = true;
+}
+
+impl Table for PlainTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = Vec<u8>;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 1;
+}
+
+#[test]
+fn test_compressed_table() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let compressed = sled_table::Writer::<CompressedTable>::from(&tree);
+    let plain = sled_table::Writer::<PlainTable>::from(&tree);
+
+    // A highly compressible value.
+    let value = vec![7u8; 4096];
+    compressed.set(&0, &value).unwrap();
+    plain.set(&0, &value).unwrap();
+
+    // Round-trip.
+    assert_eq!(compressed.get(&0).unwrap().unwrap(), value);
+    assert_eq!(compressed.iter().unwrap().next().unwrap().unwrap(), (0, value.clone()));
+    assert_eq!(compressed.del(&0).unwrap().unwrap(), value);
+    compressed.set(&0, &value).unwrap();
+
+    // Compression only affects the compressed table.
+    assert_eq!(plain.get(&0).unwrap().unwrap(), value);
+    assert!(compressed.size_bytes().unwrap() < plain.size_bytes().unwrap());
+}
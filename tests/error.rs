@@ -0,0 +1,134 @@
+extern crate bincode;
+extern crate bytekey;
+extern crate sled;
+extern crate sled_table;
+
+use sled_table::Error;
+use std::io;
+
+// One of each `Error` variant.
+fn sled_error() -> Error {
+    Error::Sled(sled::Error::Unsupported("unsupported".to_string()))
+}
+
+fn bincode_error() -> Error {
+    Error::Bincode(Box::new(bincode::ErrorKind::SizeLimit))
+}
+
+fn bytekey_error() -> Error {
+    bytekey::deserialize::<u64>(&[]).unwrap_err().into()
+}
+
+fn compression_error() -> Error {
+    Error::Compression(io::Error::new(io::ErrorKind::InvalidData, "bad frame"))
+}
+
+fn codec_error() -> Error {
+    Error::Codec(Box::new(io::Error::new(io::ErrorKind::InvalidData, "bad value")))
+}
+
+fn decode_key_error() -> Error {
+    let source = Box::new(bytekey_error());
+    Error::DecodeKey { id_bytes: vec![0], key_bytes: vec![1], source }
+}
+
+fn decode_value_error() -> Error {
+    let source = Box::new(bincode_error());
+    Error::DecodeValue { id_bytes: vec![0], key_bytes: vec![1], value_bytes: vec![2], source }
+}
+
+#[test]
+fn test_error_into_io_error() {
+    let errors = vec![
+        (sled_error(), io::ErrorKind::Other),
+        (bincode_error(), io::ErrorKind::InvalidData),
+        (bytekey_error(), io::ErrorKind::InvalidData),
+        (compression_error(), io::ErrorKind::InvalidData),
+        (codec_error(), io::ErrorKind::InvalidData),
+        (Error::ChecksumMismatch, io::ErrorKind::InvalidData),
+        (Error::RetryExhausted, io::ErrorKind::Other),
+        (Error::ZeroStep, io::ErrorKind::InvalidInput),
+        (decode_key_error(), io::ErrorKind::InvalidData),
+        (decode_value_error(), io::ErrorKind::InvalidData),
+    ];
+    for (err, kind) in errors {
+        let msg = err.to_string();
+        let io_err: io::Error = err.into();
+        assert_eq!(io_err.kind(), kind);
+        assert_eq!(io_err.to_string(), msg);
+
+        // The original error remains accessible.
+        let inner = io_err.get_ref().and_then(|inner| inner.downcast_ref::<Error>());
+        assert!(inner.is_some());
+    }
+
+    // I/O errors produced by the tree are returned unchanged.
+    let err = Error::Sled(sled::Error::Io(io::Error::new(io::ErrorKind::NotFound, "missing")));
+    let io_err: io::Error = err.into();
+    assert_eq!(io_err.kind(), io::ErrorKind::NotFound);
+    assert_eq!(io_err.to_string(), "missing");
+}
+
+#[test]
+fn test_error_classification() {
+    // (error, is_io, is_corruption, is_codec)
+    let errors = vec![
+        (sled_error(), true, false, false),
+        (Error::Sled(sled::Error::Corruption { at: 0 }), false, true, false),
+        (bincode_error(), false, false, true),
+        (bytekey_error(), false, false, true),
+        (compression_error(), false, false, true),
+        (codec_error(), false, false, true),
+        (Error::ChecksumMismatch, false, true, false),
+        (Error::RetryExhausted, false, false, false),
+        (Error::ZeroStep, false, false, false),
+        (decode_key_error(), false, true, true),
+        (decode_value_error(), false, true, true),
+    ];
+    for (err, is_io, is_corruption, is_codec) in errors {
+        assert_eq!(err.is_io(), is_io, "{:?}", err);
+        assert_eq!(err.is_corruption(), is_corruption, "{:?}", err);
+        assert_eq!(err.is_codec(), is_codec, "{:?}", err);
+    }
+}
+
+#[test]
+fn test_error_accessors() {
+    let errors = vec![
+        sled_error(),
+        bincode_error(),
+        bytekey_error(),
+        compression_error(),
+        codec_error(),
+        Error::ChecksumMismatch,
+        Error::RetryExhausted,
+        Error::ZeroStep,
+    ];
+    for err in errors {
+        let is_sled = match err {
+            Error::Sled(_) => true,
+            _ => false,
+        };
+        let is_bincode = match err {
+            Error::Bincode(_) => true,
+            _ => false,
+        };
+        let is_bytekey = match err {
+            Error::Bytekey(_) => true,
+            _ => false,
+        };
+        assert_eq!(err.as_sled().is_some(), is_sled, "{:?}", err);
+        assert_eq!(err.as_bincode().is_some(), is_bincode, "{:?}", err);
+        assert_eq!(err.as_bytekey().is_some(), is_bytekey, "{:?}", err);
+    }
+
+    // The accessors look through the context attached to decoding errors.
+    assert!(decode_key_error().as_bytekey().is_some());
+    assert!(decode_value_error().as_bincode().is_some());
+
+    // The inner error may be matched upon.
+    match sled_error().as_sled() {
+        Some(&sled::Error::Unsupported(ref msg)) => assert_eq!(msg, "unsupported"),
+        other => panic!("unexpected inner error: {:?}", other),
+    }
+}
@@ -0,0 +1,142 @@
+extern crate sled;
+extern crate sled_table;
+
+use sled_table::{Reversible, Table};
+
+// A table mapping names to unique numbers.
+struct NameTable;
+
+// The reverse of `NameTable`, mapping numbers to names.
+struct NumberTable;
+
+impl Table for NameTable {
+    type Id = u8;
+    type Key = String;
+    type Value = u32;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 0;
+}
+
+impl Table for NumberTable {
+    type Id = u8;
+    type Key = u32;
+    type Value = String;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 1;
+}
+
+impl Reversible for NameTable {
+    type ReverseTable = NumberTable;
+}
+
+impl Reversible for NumberTable {
+    type ReverseTable = NameTable;
+}
+
+#[test]
+fn test_iter_by_value() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::reversible::Writer::<NameTable>::from(&tree);
+    let entries = vec![("alpha", 30), ("beta", 10), ("delta", 20), ("gamma", 5)];
+    for &(name, number) in &entries {
+        table.set(&name.to_string(), &number).unwrap();
+    }
+
+    let reader = sled_table::reversible::Reader::<NameTable>::from(table);
+    let by_value: Vec<_> = reader.iter_by_value().unwrap().map(Result::unwrap).collect();
+    let mut expected: Vec<_> = reader
+        .iter()
+        .unwrap()
+        .map(Result::unwrap)
+        .map(|(name, number)| (number, name))
+        .collect();
+    expected.sort();
+    assert_eq!(by_value, expected);
+    assert_eq!(by_value[0], (5, "gamma".to_string()));
+    assert_eq!(reader.inv().iter().unwrap().count(), entries.len());
+}
+
+#[test]
+fn test_replace() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::reversible::Writer::<NameTable>::from(&tree);
+    let (a, b) = ("a".to_string(), "b".to_string());
+    assert_eq!(table.replace(&a, &1).unwrap(), None);
+
+    // Remap `a -> 1` to `a -> 2`.
+    assert_eq!(table.replace(&a, &2).unwrap(), Some(1));
+    let inv = table.inv();
+    assert_eq!(table.get(&a).unwrap(), Some(2));
+    assert_eq!(inv.get(&2).unwrap(), Some(a.clone()));
+    assert_eq!(inv.get(&1).unwrap(), None);
+
+    // Claiming a value held by another key removes that key's mapping.
+    table.set(&b, &3).unwrap();
+    assert_eq!(table.replace(&b, &2).unwrap(), Some(3));
+    assert_eq!(table.get(&a).unwrap(), None);
+    assert_eq!(inv.get(&3).unwrap(), None);
+    assert_eq!(inv.get(&2).unwrap(), Some(b.clone()));
+    assert_eq!(table.iter().unwrap().count(), 1);
+    assert_eq!(inv.iter().unwrap().count(), 1);
+
+    // Replacing a pair with itself leaves it intact.
+    assert_eq!(table.replace(&b, &2).unwrap(), Some(2));
+    assert_eq!(inv.get(&2).unwrap(), Some(b.clone()));
+    assert_eq!(table.iter().unwrap().count(), 1);
+    assert_eq!(inv.iter().unwrap().count(), 1);
+}
+
+#[test]
+fn test_del_by_value() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::reversible::Writer::<NameTable>::from(&tree);
+    let (a, b) = ("a".to_string(), "b".to_string());
+    table.set(&a, &1).unwrap();
+    table.set(&b, &2).unwrap();
+
+    assert_eq!(table.del_by_value(&1).unwrap(), Some(a.clone()));
+    assert_eq!(table.get(&a).unwrap(), None);
+    assert_eq!(table.inv().get(&1).unwrap(), None);
+    assert_eq!(table.del_by_value(&1).unwrap(), None);
+
+    // The other pair is left untouched.
+    assert_eq!(table.get(&b).unwrap(), Some(2));
+    assert_eq!(table.inv().get(&2).unwrap(), Some(b.clone()));
+}
+
+#[test]
+fn test_bijection_stats() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::reversible::Writer::<NameTable>::from(&tree);
+    for &(name, number) in &[("a", 1), ("b", 2), ("c", 3)] {
+        table.set(&name.to_string(), &number).unwrap();
+    }
+    let reader = sled_table::reversible::Reader::<NameTable>::from(table.clone());
+    let stats = reader.bijection_stats().unwrap();
+    assert_eq!(stats.matched, 3);
+    assert_eq!((stats.forward_only, stats.reverse_only), (0, 0));
+
+    // Desync the pair by writing to each table directly.
+    let names = sled_table::Writer::<NameTable>::from(&tree);
+    let numbers = sled_table::Writer::<NumberTable>::from(&tree);
+    names.set(&"d".to_string(), &4).unwrap();
+    names.set(&"e".to_string(), &5).unwrap();
+    numbers.set(&6, &"f".to_string()).unwrap();
+    numbers.set(&3, &"a".to_string()).unwrap();
+    let stats = reader.bijection_stats().unwrap();
+    let expected = sled_table::reversible::BijectionStats {
+        matched: 2,
+        forward_only: 3,
+        reverse_only: 2,
+    };
+    assert_eq!(stats, expected);
+
+    // Only one of many keys sharing the same value may be the value's inverse.
+    names.set(&"g".to_string(), &2).unwrap();
+    let stats = reader.bijection_stats().unwrap();
+    assert_eq!((stats.matched, stats.forward_only, stats.reverse_only), (2, 4, 2));
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "rand")]
+
+extern crate rand;
+extern crate sled;
+extern crate sled_table;
+
+use rand::{SeedableRng, StdRng};
+use sled_table::Table;
+use std::collections::BTreeSet;
+
+struct SampleTable;
+
+impl Table for SampleTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = u32;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 0;
+}
+
+#[test]
+fn test_sample() {
+    let tree = sled_table::MemStore::new();
+    let writer = sled_table::Writer::<SampleTable, _>::from(&tree);
+    let mut rng = StdRng::from_seed([7; 32]);
+
+    // An empty table has nothing to sample.
+    assert!(writer.sample(&mut rng).unwrap().is_none());
+    assert!(writer.sample_scan(&mut rng).unwrap().is_none());
+
+    let keys = [3, 64, 100, 180, 250];
+    for &k in &keys {
+        writer.set(&k, &(k as u32 * 10)).unwrap();
+    }
+
+    // Repeated sampling should eventually select every entry.
+    let (mut sampled, mut scanned) = (BTreeSet::new(), BTreeSet::new());
+    for _ in 0..1_000 {
+        let (k, v) = writer.sample(&mut rng).unwrap().unwrap();
+        assert_eq!(v, k as u32 * 10);
+        sampled.insert(k);
+        let (k, v) = writer.sample_scan(&mut rng).unwrap().unwrap();
+        assert_eq!(v, k as u32 * 10);
+        scanned.insert(k);
+    }
+    let all: BTreeSet<u8> = keys.iter().cloned().collect();
+    assert_eq!(sampled, all);
+    assert_eq!(scanned, all);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "async")]
+
+extern crate futures;
+extern crate sled_table;
+
+use futures::{Future, Stream};
+use sled_table::Table;
+
+struct StreamTable;
+
+impl Table for StreamTable {
+    type Id = u8;
+    type Key = u16;
+    type Value = String;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 0;
+}
+
+#[test]
+fn test_stream() {
+    let tree = sled_table::MemStore::new();
+    let writer = sled_table::Writer::<StreamTable, _>::from(&tree);
+
+    // An empty table yields an empty stream.
+    assert!(writer.stream().unwrap().collect().wait().unwrap().is_empty());
+
+    for key in &[300, 2, 45, 1000] {
+        writer.set(key, &format!("value {}", key)).unwrap();
+    }
+    let streamed = writer.stream().unwrap().collect().wait().unwrap();
+    let iterated = writer.iter().unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(streamed, iterated);
+    assert_eq!(streamed.len(), 4);
+}
@@ -1,8 +1,17 @@
+extern crate bincode;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
 extern crate sled;
 extern crate sled_table;
 
 use sled_table::Table;
+use std::cmp;
+use std::collections::BTreeMap;
 use std::mem;
+use std::num::NonZeroU16;
+use std::sync::Arc;
+use std::thread;
 
 // A type that we may use as a test `Table`.
 pub struct ByteTable;
@@ -12,9 +21,204 @@ impl Table for ByteTable {
     type Id = u8;
     type Key = Vec<u8>;
     type Value = Vec<u8>;
+    type Codec = sled_table::Bincode;
     const ID: Self::Id = 0;
 }
 
+// Another table with the same layout as `ByteTable`.
+pub struct OtherByteTable;
+
+impl Table for OtherByteTable {
+    type Id = u8;
+    type Key = Vec<u8>;
+    type Value = Vec<u8>;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 2;
+}
+
+// A table whose keys are made up of a group and an item within that group.
+pub struct GroupTable;
+
+impl Table for GroupTable {
+    type Id = u8;
+    type Key = (u16, u16);
+    type Value = u32;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 3;
+}
+
+// A table of integer counters.
+pub struct CounterTable;
+
+impl Table for CounterTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = u64;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 4;
+}
+
+// A table whose merged values keep the maximum.
+pub struct MaxTable;
+
+impl Table for MaxTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = u64;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 5;
+    fn merge(existing: Option<u64>, operand: u64) -> Option<u64> {
+        Some(existing.map_or(operand, |existing| cmp::max(existing, operand)))
+    }
+}
+
+// A table keyed by `char`s.
+pub struct CharTable;
+
+impl Table for CharTable {
+    type Id = u8;
+    type Key = char;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 6;
+}
+
+// A table keyed by `bool`s.
+pub struct BoolTable;
+
+impl Table for BoolTable {
+    type Id = u8;
+    type Key = bool;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 7;
+}
+
+// A table whose values are limited to a small size.
+pub struct LimitedTable;
+
+impl Table for LimitedTable {
+    type Id = u8;
+    type Key = u8;
+    type Value = Vec<u8>;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 8;
+    fn bincode_config() -> bincode::Config {
+        let mut config = bincode::config();
+        config.limit(64);
+        config
+    }
+}
+
+// A table keyed by non-zero integers.
+pub struct NonZeroTable;
+
+impl Table for NonZeroTable {
+    type Id = u8;
+    type Key = NonZeroU16;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 9;
+}
+
+// A key whose `Ord` implementation is the reverse of its encoded order.
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Desc(u8);
+
+impl Ord for Desc {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for Desc {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// A table keyed by `Desc`.
+pub struct DescTable;
+
+impl Table for DescTable {
+    type Id = u8;
+    type Key = Desc;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 10;
+}
+
+// Two distinct tables that mistakenly share the same `ID`.
+pub struct CollidingTableA;
+pub struct CollidingTableB;
+pub struct CollidingTableC;
+
+impl Table for CollidingTableA {
+    type Id = u8;
+    type Key = u8;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 200;
+}
+
+impl Table for CollidingTableB {
+    type Id = u8;
+    type Key = u8;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 200;
+}
+
+impl Table for CollidingTableC {
+    type Id = (u8, u8);
+    type Key = u8;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = (200, 1);
+}
+
+// The first version of a schema-versioned value.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct PointV1 {
+    x: u8,
+}
+
+// The second version of a schema-versioned value.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct PointV2 {
+    x: u16,
+    y: u16,
+}
+
+// A table of `PointV1`s.
+struct PointTableV1;
+
+// The same table after bumping the schema to `PointV2`.
+struct PointTableV2;
+
+impl Table for PointTableV1 {
+    type Id = u8;
+    type Key = u8;
+    type Value = PointV1;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 1;
+    const VERSION: Option<u8> = Some(1);
+}
+
+impl Table for PointTableV2 {
+    type Id = u8;
+    type Key = u8;
+    type Value = PointV2;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = 1;
+    const VERSION: Option<u8> = Some(2);
+    fn migrate(version: u8, bytes: &[u8]) -> sled_table::Result<PointV2> {
+        assert_eq!(version, 1);
+        let PointV1 { x } = bincode::deserialize(bytes)?;
+        Ok(PointV2 { x: x as u16, y: 0 })
+    }
+}
+
 #[test]
 fn test_writer() {
     let config = sled::ConfigBuilder::new().temporary(true).build();
@@ -59,6 +263,1108 @@ fn test_writer() {
     assert_eq!(table.del(&b_key).unwrap(), None);
 }
 
+#[test]
+fn test_set_entry() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+
+    let entries: Vec<(u8, u64)> = (0..5).map(|i| (i, i as u64 * 2)).collect();
+    entries.iter().map(|entry| table.set_entry(entry)).collect::<sled_table::Result<()>>().unwrap();
+    (5..10u8)
+        .map(|i| (i, i as u64 * 2))
+        .map(|(k, v)| table.set_owned(k, v))
+        .collect::<sled_table::Result<()>>()
+        .unwrap();
+
+    let collected: Vec<_> = table.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(collected, (0..10u8).map(|i| (i, i as u64 * 2)).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_sync() {
+    let dir = std::env::temp_dir().join(format!("sled-table-test-sync-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("db");
+    {
+        let config = sled::ConfigBuilder::new().path(&path).build();
+        let tree = sled::Tree::start(config).unwrap();
+        let table = sled_table::Writer::<CounterTable>::from(&tree);
+        table.set(&0, &42).unwrap();
+        table.sync().unwrap();
+    }
+    {
+        let config = sled::ConfigBuilder::new().path(&path).build();
+        let tree = sled::Tree::start(config).unwrap();
+        let table = sled_table::Reader::<CounterTable>::from(&tree);
+        assert_eq!(table.get(&0).unwrap(), Some(42));
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_swap() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = Arc::new(sled::Tree::start(config).unwrap());
+    let table = sled_table::Writer::<CounterTable>::from(&*tree);
+    assert_eq!(table.swap(&0, &0).unwrap(), None);
+    assert_eq!(table.swap(&0, &1).unwrap(), Some(0));
+
+    // Two threads swap distinct values into the same key.
+    let handles: Vec<_> = (0..2u64)
+        .map(|t| {
+            let tree = tree.clone();
+            thread::spawn(move || {
+                let table = sled_table::Writer::<CounterTable>::from(&*tree);
+                (0..100u64)
+                    .map(|i| table.swap(&0, &(2 + t * 100 + i)).unwrap().unwrap())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    let mut values: Vec<u64> = handles.into_iter().flat_map(|h| h.join().unwrap()).collect();
+
+    // Every value that was swapped in must be swapped out exactly once, or remain in the table.
+    values.push(table.get(&0).unwrap().unwrap());
+    values.sort();
+    assert_eq!(values, (1..202).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_with_retries() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+
+    // Persistent contention exhausts the retries.
+    let mut attempts = 0;
+    let res: sled_table::Result<()> = table.with_retries(5, || {
+        attempts += 1;
+        Ok(None)
+    });
+    match res {
+        Err(sled_table::Error::RetryExhausted) => (),
+        other => panic!("expected `RetryExhausted`, found {:?}", other),
+    }
+    assert_eq!(attempts, 5);
+
+    // Succeeds once contention subsides.
+    let mut attempts = 0;
+    let res = table.with_retries(5, || {
+        attempts += 1;
+        Ok(if attempts == 3 { Some(attempts) } else { None })
+    });
+    assert_eq!(res.unwrap(), 3);
+}
+
+#[test]
+fn test_raw_values() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+
+    // Raw bytes written are readable via the typed API.
+    let value = vec![1, 2, 3];
+    let value_bytes = bincode::serialize(&value).unwrap();
+    table.set_raw(&vec![0], &value_bytes).unwrap();
+    assert_eq!(table.get(&vec![0]).unwrap(), Some(value.clone()));
+    assert_eq!(table.get_raw(&vec![0]).unwrap(), Some(value_bytes));
+
+    // Typed values written are readable as raw bytes.
+    table.set(&vec![1], &value).unwrap();
+    let raw = table.get_raw(&vec![1]).unwrap().unwrap();
+    assert_eq!(raw, sled_table::write_value::<ByteTable>(&value).unwrap());
+    assert_eq!(table.get_raw(&vec![2]).unwrap(), None);
+}
+
+#[test]
+fn test_cas_raw() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let key = vec![0];
+    let a = sled_table::write_value::<ByteTable>(&vec![1]).unwrap();
+    let b = sled_table::write_value::<ByteTable>(&vec![2, 3]).unwrap();
+
+    // Insert via raw CAS and read back via the typed API.
+    assert_eq!(table.cas_raw(&key, None, Some(&a)).unwrap(), Ok(()));
+    assert_eq!(table.get(&key).unwrap(), Some(vec![1]));
+
+    // A mismatched `old` returns the current raw bytes and leaves the value unchanged.
+    assert_eq!(table.cas_raw(&key, None, Some(&b)).unwrap(), Err(Some(a.clone())));
+    assert_eq!(table.cas_raw(&key, Some(&b), None).unwrap(), Err(Some(a.clone())));
+    assert_eq!(table.get(&key).unwrap(), Some(vec![1]));
+
+    // Swap to a typed value's bytes, then remove the entry.
+    assert_eq!(table.cas_raw(&key, Some(&a), Some(&b)).unwrap(), Ok(()));
+    assert_eq!(table.get(&key).unwrap(), Some(vec![2, 3]));
+    assert_eq!(table.cas_raw(&key, Some(&b), None).unwrap(), Ok(()));
+    assert_eq!(table.get(&key).unwrap(), None);
+    assert_eq!(table.cas_raw(&key, Some(&b), None).unwrap(), Err(None));
+}
+
+#[test]
+fn test_get_entry() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<GroupTable>::from(&tree);
+    table.set(&(1, 2), &3).unwrap();
+
+    let (key_bytes, value) = table.get_entry(&(1, 2)).unwrap().unwrap();
+    assert_eq!(key_bytes, sled_table::write_key::<GroupTable>(&(1, 2)).unwrap());
+    assert_eq!(value, 3);
+    assert!(table.get_entry(&(2, 1)).unwrap().is_none());
+
+    // The key bytes may be used as a cursor.
+    table.set(&(1, 3), &4).unwrap();
+    let mut iter = table.iter_from_raw(&key_bytes).unwrap();
+    assert_eq!(iter.next().unwrap().unwrap(), ((1, 3), 4));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_get_or_default() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    table.set(&1, &42).unwrap();
+    assert_eq!(table.get_or_default(&0).unwrap(), 0);
+    assert_eq!(table.get_or_default(&1).unwrap(), 42);
+}
+
+#[test]
+fn test_get_if() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    table.set(&1, &42).unwrap();
+    assert_eq!(table.get_if(&1, |&v| v > 40).unwrap(), Some(42));
+    assert_eq!(table.get_if(&1, |&v| v > 50).unwrap(), None);
+    assert_eq!(table.get(&1).unwrap(), Some(42));
+
+    // The predicate is not called for a missing key.
+    assert_eq!(table.get_if(&0, |_| panic!("no value to test")).unwrap(), None);
+}
+
+#[test]
+fn test_remove() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    table.set(&vec![1], &vec![10]).unwrap();
+    table.set(&vec![2], &vec![20]).unwrap();
+
+    assert!(table.remove(&vec![1]).unwrap());
+    assert!(!table.remove(&vec![1]).unwrap());
+    assert!(!table.remove(&vec![3]).unwrap());
+    assert_eq!(table.get(&vec![1]).unwrap(), None);
+    assert_eq!(table.get(&vec![2]).unwrap(), Some(vec![20]));
+}
+
+#[test]
+fn test_del_batch() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+    for k in 0..5 {
+        table.set(&k, &(k as u64)).unwrap();
+    }
+
+    // Only the keys that were present are counted.
+    assert_eq!(table.del_batch(vec![1, 3, 7, 9, 3]).unwrap(), 2);
+    let remaining: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(remaining, vec![0, 2, 4]);
+    assert_eq!(table.del_batch(vec![]).unwrap(), 0);
+}
+
+#[test]
+fn test_upsert_all() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    table.set(&1, &10).unwrap();
+    table.set(&3, &30).unwrap();
+
+    let entries = vec![(0, 0), (1, 11), (2, 20), (3, 31)];
+    assert_eq!(table.upsert_all(entries).unwrap(), vec![0, 2]);
+    let collected: Vec<_> = table.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(collected, vec![(0, 0), (1, 11), (2, 20), (3, 31)]);
+    assert!(table.upsert_all(vec![(0, 1)]).unwrap().is_empty());
+}
+
+#[test]
+fn test_clear_range() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    for k in 0..10 {
+        table.set(&k, &(k as u64)).unwrap();
+        other.set(&k, &(k as u64)).unwrap();
+    }
+
+    // Only keys within `start..end` are removed.
+    assert_eq!(table.clear_range(&3, &7).unwrap(), 4);
+    let remaining: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(remaining, vec![0, 1, 2, 7, 8, 9]);
+    assert_eq!(table.clear_range(&3, &7).unwrap(), 0);
+    assert_eq!(table.clear_range(&5, &5).unwrap(), 0);
+
+    // Clearing up to the end of the table stops at its boundary.
+    assert_eq!(table.clear_range(&8, &255).unwrap(), 2);
+    let remaining: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(remaining, vec![0, 1, 2, 7]);
+    assert_eq!(other.iter().unwrap().count(), 10);
+}
+
+#[test]
+fn test_replace_all() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    table.upsert_all(vec![(0, 0), (1, 10), (2, 20)]).unwrap();
+    other.set(&0, &0).unwrap();
+
+    table.replace_all(vec![(1, 11), (5, 50), (3, 30), (5, 51)]).unwrap();
+    let collected: Vec<_> = table.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(collected, vec![(1, 11), (3, 30), (5, 51)]);
+    assert_eq!(other.get(&0).unwrap(), Some(0));
+
+    table.replace_all(vec![]).unwrap();
+    assert!(table.iter().unwrap().next().is_none());
+    assert_eq!(other.get(&0).unwrap(), Some(0));
+
+    // An entry that fails to serialize leaves the table unchanged.
+    let limited = sled_table::Writer::<LimitedTable, sled_table::MemStore>::from(&store);
+    limited.set(&0, &vec![0]).unwrap();
+    assert!(limited.replace_all(vec![(1, vec![1]), (2, vec![2; 100])]).is_err());
+    let collected: Vec<_> = limited.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(collected, vec![(0, vec![0])]);
+}
+
+#[test]
+fn test_insert_or_merge() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let union = |mut tags: Vec<u8>, new_tags: Vec<u8>| {
+        for tag in new_tags {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    };
+    let key = vec![0];
+
+    // With no existing value, the new value is inserted as is.
+    assert_eq!(table.insert_or_merge(&key, vec![1, 2, 3], union).unwrap(), vec![1, 2, 3]);
+    assert_eq!(table.insert_or_merge(&key, vec![3, 4], union).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(table.get(&key).unwrap(), Some(vec![1, 2, 3, 4]));
+    assert_eq!(table.get(&vec![1]).unwrap(), None);
+}
+
+#[test]
+fn test_get_and_modify() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let key = vec![0];
+
+    // Insertion reports no previous value.
+    let (old, new) = table.get_and_modify(&key, |old| {
+        assert!(old.is_none());
+        Some(vec![1])
+    }).unwrap();
+    assert_eq!((old, new), (None, Some(vec![1])));
+
+    // An overwrite reports both the old and new values.
+    let (old, new) = table.get_and_modify(&key, |old| {
+        let mut value = old.unwrap().clone();
+        value.push(2);
+        Some(value)
+    }).unwrap();
+    assert_eq!((old, new), (Some(vec![1]), Some(vec![1, 2])));
+    assert_eq!(table.get(&key).unwrap(), Some(vec![1, 2]));
+
+    // Returning `None` removes the entry.
+    let (old, new) = table.get_and_modify(&key, |_| None).unwrap();
+    assert_eq!((old, new), (Some(vec![1, 2]), None));
+    assert_eq!(table.get(&key).unwrap(), None);
+}
+
+#[test]
+fn test_set_if_absent() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = Arc::new(sled::Tree::start(config).unwrap());
+    let table = sled_table::Writer::<CounterTable>::from(&*tree);
+    assert!(table.set_if_absent(&0, &1).unwrap());
+    assert!(!table.set_if_absent(&0, &2).unwrap());
+    assert_eq!(table.get(&0).unwrap(), Some(1));
+
+    // Many threads race to claim the same key.
+    let handles: Vec<_> = (0..8u64)
+        .map(|t| {
+            let tree = tree.clone();
+            thread::spawn(move || {
+                let table = sled_table::Writer::<CounterTable>::from(&*tree);
+                table.set_if_absent(&1, &t).unwrap()
+            })
+        })
+        .collect();
+    let claimed: Vec<bool> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(claimed.iter().filter(|&&c| c).count(), 1);
+    let winner = claimed.iter().position(|&c| c).unwrap() as u64;
+    assert_eq!(table.get(&1).unwrap(), Some(winner));
+}
+
+#[test]
+fn test_move_key() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+    table.set(&0, &10).unwrap();
+    table.set(&2, &20).unwrap();
+
+    assert!(table.move_key(&0, &1).unwrap());
+    assert_eq!(table.get(&0).unwrap(), None);
+    assert_eq!(table.get(&1).unwrap(), Some(10));
+
+    // Existing values under `to` are replaced.
+    assert!(table.move_key(&1, &2).unwrap());
+    assert_eq!(table.get(&1).unwrap(), None);
+    assert_eq!(table.get(&2).unwrap(), Some(10));
+
+    // Moving a missing key or a key onto itself changes nothing.
+    assert!(!table.move_key(&0, &2).unwrap());
+    assert!(table.move_key(&2, &2).unwrap());
+    let entries: Vec<_> = table.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(entries, vec![(2, 10)]);
+}
+
+#[test]
+fn test_drain() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    let other = sled_table::Writer::<OtherByteTable>::from(&tree);
+    for i in 0..10u8 {
+        table.set(&vec![i], &vec![i; 2]).unwrap();
+    }
+    other.set(&vec![0], &vec![0]).unwrap();
+
+    let drained: Vec<_> = table.drain().unwrap().map(Result::unwrap).collect();
+    let expected: Vec<_> = (0..10u8).map(|i| (vec![i], vec![i; 2])).collect();
+    assert_eq!(drained, expected);
+    assert!(table.iter().unwrap().next().is_none());
+    assert_eq!(other.get(&vec![0]).unwrap(), Some(vec![0]));
+
+    // Entries that are not yielded remain within the table.
+    for i in 0..4u8 {
+        table.set(&vec![i], &vec![]).unwrap();
+    }
+    assert_eq!(table.drain().unwrap().take(3).count(), 3);
+    assert_eq!(table.iter().unwrap().map(|res| res.unwrap().0).collect::<Vec<_>>(), vec![vec![3]]);
+}
+
+#[test]
+fn test_compare_and_delete() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+    table.set(&0, &1).unwrap();
+
+    // Another writer changes the value before we get to delete it.
+    table.set(&0, &2).unwrap();
+    assert!(!table.compare_and_delete(&0, &1).unwrap());
+    assert_eq!(table.get(&0).unwrap(), Some(2));
+
+    assert!(table.compare_and_delete(&0, &2).unwrap());
+    assert_eq!(table.get(&0).unwrap(), None);
+    assert!(!table.compare_and_delete(&0, &2).unwrap());
+}
+
+#[test]
+fn test_merge() {
+    let config = sled::ConfigBuilder::new()
+        .temporary(true)
+        .merge_operator(sled_table::merge_operator::<MaxTable>)
+        .build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<MaxTable>::from(&tree);
+    for &operand in &[3, 8, 2, 8, 5] {
+        table.merge(&0, &operand).unwrap();
+    }
+    table.merge(&1, &4).unwrap();
+    assert_eq!(table.get(&0).unwrap(), Some(8));
+    assert_eq!(table.get(&1).unwrap(), Some(4));
+
+    // The same operator may be used with a `MemStore`.
+    let store = sled_table::MemStore::with_merge_operator(sled_table::merge_operator::<MaxTable>);
+    let table = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    table.merge(&0, &3).unwrap();
+    table.merge(&0, &1).unwrap();
+    assert_eq!(table.get(&0).unwrap(), Some(3));
+}
+
+#[test]
+fn test_scan_values() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+    let next_table = sled_table::Writer::<MaxTable>::from(&tree);
+    for i in (0..10u8).map(|i| i * 2) {
+        table.set(&i, &(i as u64 * 10)).unwrap();
+    }
+    next_table.set(&0, &0).unwrap();
+
+    let values = |k: u8| -> Vec<u64> {
+        table.scan_values(&k).unwrap().map(Result::unwrap).collect()
+    };
+    assert_eq!(values(14), vec![140, 160, 180]);
+    assert_eq!(values(15), vec![160, 180]);
+    assert_eq!(values(0).len(), 10);
+    assert!(values(19).is_empty());
+}
+
+#[test]
+fn test_values_range() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let next_table = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    for i in (0..10u8).map(|i| i * 2) {
+        table.set(&i, &(i as u64 * 10)).unwrap();
+    }
+    next_table.set(&0, &0).unwrap();
+
+    let values = |start: u8, end: u8| -> Vec<u64> {
+        table.values_range(&start, &end).unwrap().map(Result::unwrap).collect()
+    };
+    let keyed = |start: u8, end: u8| -> Vec<u64> {
+        table.collect_range(&start, &end).unwrap().into_iter().map(|(_, v)| v).collect()
+    };
+    assert_eq!(values(3, 9), vec![40, 60, 80]);
+    for &(start, end) in &[(3, 9), (0, 18), (4, 4), (15, 255), (19, 255)] {
+        assert_eq!(values(start, end), keyed(start, end));
+    }
+}
+
+#[test]
+fn test_collect_range() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<GroupTable>::from(&tree);
+    let keys = vec![(1, 1), (1, 5), (2, 0), (2, 3), (2, 65535), (3, 0)];
+    for (i, key) in keys.iter().enumerate() {
+        table.set(key, &(i as u32)).unwrap();
+    }
+
+    let map = table.collect_range(&(1, 5), &(2, 65535)).unwrap();
+    let expected: BTreeMap<_, _> = vec![((1, 5), 1), ((2, 0), 2), ((2, 3), 3)]
+        .into_iter()
+        .collect();
+    assert_eq!(map, expected);
+    assert_eq!(table.collect_range(&(0, 0), &(4, 0)).unwrap().len(), keys.len());
+    assert!(table.collect_range(&(2, 1), &(2, 2)).unwrap().is_empty());
+
+    // The range is bounded by encoded order, as within `scan_range`, rather than by `Ord`.
+    let desc = sled_table::Writer::<DescTable>::from(&tree);
+    for i in 0..8 {
+        desc.set(&Desc(i), &()).unwrap();
+    }
+    let map = desc.collect_range(&Desc(2), &Desc(5)).unwrap();
+    assert_eq!(map.keys().map(|k| k.0).collect::<Vec<_>>(), vec![4, 3, 2]);
+    assert_eq!(desc.scan_range(&Desc(2), &Desc(5)).unwrap().count(), map.len());
+}
+
+#[test]
+fn test_map_values() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    table.set(&vec![0], &vec![1, 2, 3]).unwrap();
+    table.set(&vec![1], &vec![]).unwrap();
+    table.set(&vec![2], &vec![4; 10]).unwrap();
+
+    let lens: Vec<_> = table.map_values(|v| v.len()).unwrap().map(Result::unwrap).collect();
+    assert_eq!(lens, vec![(vec![0], 3), (vec![1], 0), (vec![2], 10)]);
+}
+
+#[test]
+fn test_reader_into_iter() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    for k in 0..4 {
+        table.set(&k, &(k as u64 * 2)).unwrap();
+    }
+
+    let reader: &sled_table::Reader<CounterTable, sled_table::MemStore> = &table;
+    let mut collected = vec![];
+    for entry in reader {
+        collected.push(entry.unwrap());
+    }
+    assert_eq!(collected, vec![(0, 0), (1, 2), (2, 4), (3, 6)]);
+}
+
+#[test]
+fn test_any_in_range() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    for &k in &[10, 20, 30] {
+        table.set(&k, &0).unwrap();
+    }
+    other.set(&0, &0).unwrap();
+
+    // Empty ranges and ranges without entries.
+    assert!(!table.any_in_range(&20, &20).unwrap());
+    assert!(!table.any_in_range(&11, &20).unwrap());
+    assert!(!table.any_in_range(&0, &10).unwrap());
+
+    // Ranges containing entries.
+    assert!(table.any_in_range(&11, &21).unwrap());
+    assert!(table.any_in_range(&30, &31).unwrap());
+
+    // A range just past the last key does not run into the following table.
+    assert!(!table.any_in_range(&31, &255).unwrap());
+}
+
+#[test]
+fn test_contains_value() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    other.set(&0, &99).unwrap();
+    assert!(!table.contains_value(&0).unwrap());
+
+    for k in 0..5 {
+        table.set(&k, &(k as u64 * 10)).unwrap();
+    }
+    assert!(table.contains_value(&0).unwrap());
+    assert!(table.contains_value(&40).unwrap());
+    assert!(!table.contains_value(&15).unwrap());
+    // Values within other tables are not considered.
+    assert!(!table.contains_value(&99).unwrap());
+}
+
+#[test]
+fn test_scan_range() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    other.set(&0, &0).unwrap();
+    for k in &[1, 3, 5, 7, 9] {
+        table.set(k, &(*k as u64)).unwrap();
+    }
+    let range = |lo: u8, hi: u8| -> Vec<u8> {
+        table.scan_range(&lo, &hi).unwrap().map(|res| res.unwrap().0).collect()
+    };
+
+    // `lo` is inclusive while `hi` is exclusive.
+    assert_eq!(range(3, 7), vec![3, 5]);
+    assert_eq!(range(2, 8), vec![3, 5, 7]);
+    assert_eq!(range(0, 255), vec![1, 3, 5, 7, 9]);
+    assert!(range(5, 5).is_empty());
+    assert!(range(7, 3).is_empty());
+    assert!(range(10, 255).is_empty());
+}
+
+#[test]
+fn test_scan_while() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let values = [3, 5, 2, 11, 4, 20];
+    for (k, &v) in values.iter().enumerate() {
+        table.set(&(k as u8), &v).unwrap();
+    }
+
+    // Stop at the first value exceeding the threshold.
+    let mut iter = table.scan_while(&1, |_, &v| v <= 10).unwrap();
+    let collected: Vec<_> = iter.by_ref().map(Result::unwrap).collect();
+    assert_eq!(collected, vec![(1, 5), (2, 2)]);
+    assert!(iter.next().is_none());
+
+    // Stopping immediately and never stopping.
+    assert!(table.scan_while(&0, |_, _| false).unwrap().next().is_none());
+    assert_eq!(table.scan_while(&0, |_, _| true).unwrap().count(), values.len());
+}
+
+#[test]
+fn test_iter_size_hint() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<OtherByteTable, sled_table::MemStore>::from(&store);
+    for i in 0..10u8 {
+        table.set(&vec![i], &vec![i]).unwrap();
+        other.set(&vec![i], &vec![i]).unwrap();
+    }
+
+    // The hint must bound the number of remaining entries.
+    let mut iter = table.iter().unwrap();
+    for remaining in (0..11).rev() {
+        let (lower, upper) = iter.size_hint();
+        assert!(lower <= remaining);
+        assert!(upper.map_or(true, |upper| remaining <= upper));
+        iter.next();
+    }
+    let entries: Vec<_> = table.iter().unwrap().collect();
+    assert_eq!(entries.len(), 10);
+}
+
+#[test]
+fn test_iter_size_hint_sled() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    let other = sled_table::Writer::<OtherByteTable>::from(&tree);
+    for i in 0..10u8 {
+        table.set(&vec![i], &vec![i]).unwrap();
+        other.set(&vec![i], &vec![i]).unwrap();
+    }
+
+    // sled provides no bound of its own, so the hint is valid but unbounded.
+    let mut iter = table.iter().unwrap();
+    for remaining in (0..11).rev() {
+        let (lower, upper) = iter.size_hint();
+        assert!(lower <= remaining);
+        assert!(upper.map_or(true, |upper| remaining <= upper));
+        iter.next();
+    }
+    assert_eq!(table.iter().unwrap().size_hint(), (0, None));
+}
+
+#[test]
+fn test_iter_peekable() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<OtherByteTable, sled_table::MemStore>::from(&store);
+    for i in 0..3u8 {
+        table.set(&vec![i], &vec![i * 2]).unwrap();
+    }
+    other.set(&vec![0], &vec![0]).unwrap();
+
+    // Repeated peeks return the same entry, which is then yielded by `next`.
+    let mut iter = table.iter_peekable().unwrap();
+    for i in 0..3u8 {
+        let peeked = iter.peek().unwrap().as_ref().unwrap().clone();
+        assert_eq!(peeked, (vec![i], vec![i * 2]));
+        assert_eq!(iter.peek().unwrap().as_ref().unwrap(), &peeked);
+        assert_eq!(iter.size_hint().0, 1);
+        assert_eq!(iter.next().unwrap().unwrap(), peeked);
+    }
+    assert!(iter.peek().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_nth() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    assert_eq!(table.nth(0).unwrap(), None);
+
+    for i in 0..10 {
+        table.set(&vec![i], &vec![i, i]).unwrap();
+    }
+    assert_eq!(table.nth(0).unwrap(), table.min().unwrap());
+    assert_eq!(table.nth(4).unwrap(), Some((vec![4], vec![4, 4])));
+    assert_eq!(table.nth(9).unwrap(), table.iter().unwrap().last().map(Result::unwrap));
+    assert_eq!(table.nth(10).unwrap(), None);
+}
+
+#[test]
+fn test_min_and_max_key() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    assert_eq!(table.min_key().unwrap(), None);
+    assert_eq!(table.max_key().unwrap(), None);
+
+    // Entries within neighbouring tables are not considered.
+    other.set(&0, &0).unwrap();
+    other.set(&255, &0).unwrap();
+    table.set(&0, &0).unwrap();
+    assert_eq!(table.min_key().unwrap(), Some(0));
+    assert_eq!(table.max_key().unwrap(), Some(0));
+
+    for &k in &[7, 200, 42, 3] {
+        table.set(&k, &(k as u64 * 10)).unwrap();
+    }
+    table.del(&0).unwrap();
+    assert_eq!(table.min_key().unwrap(), table.min().unwrap().map(|(k, _)| k));
+    assert_eq!(table.max_key().unwrap(), table.max().unwrap().map(|(k, _)| k));
+    assert_eq!(table.min_key().unwrap(), Some(3));
+    assert_eq!(table.max_key().unwrap(), Some(200));
+}
+
+#[test]
+fn test_max_raw() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    other.set(&255, &0).unwrap();
+    assert_eq!(table.max_raw().unwrap(), None);
+
+    table.set(&0, &7).unwrap();
+    let (key_bytes, value_bytes) = table.max_raw().unwrap().unwrap();
+    assert_eq!(key_bytes, sled_table::write_key::<CounterTable>(&0).unwrap());
+    assert_eq!(value_bytes, table.get_raw(&0).unwrap().unwrap());
+
+    for &k in &[7, 200, 42] {
+        table.set(&k, &(k as u64 * 10)).unwrap();
+    }
+    let (max_key, _) = table.max().unwrap().unwrap();
+    let (key_bytes, value_bytes) = table.max_raw().unwrap().unwrap();
+    assert_eq!(key_bytes, sled_table::write_key::<CounterTable>(&max_key).unwrap());
+    assert_eq!(value_bytes, table.get_raw(&max_key).unwrap().unwrap());
+
+    // The key bytes may be used to resume iteration after the greatest entry.
+    assert!(table.iter_bytes_from_raw(&key_bytes).unwrap().next().is_none());
+}
+
+#[test]
+fn test_try_fold() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+    assert_eq!(table.try_fold(0, |sum, (_, v)| sum + v).unwrap(), 0);
+    for i in 0..10u8 {
+        table.set(&i, &(i as u64 * 10)).unwrap();
+    }
+    assert_eq!(table.try_fold(0, |sum, (_, v)| sum + v).unwrap(), 450);
+    let keys = table
+        .try_fold(vec![], |mut keys, (k, _)| {
+            keys.push(k);
+            keys
+        })
+        .unwrap();
+    assert_eq!(keys, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_try_for_each() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    for i in 0..10u8 {
+        table.set(&i, &(i as u64 * 10)).unwrap();
+    }
+    let mut keys = vec![];
+    table.try_for_each(|k, _| {
+        keys.push(k);
+        Ok(())
+    }).unwrap();
+    assert_eq!(keys, (0..10).collect::<Vec<_>>());
+
+    // An error returned by the closure ends iteration and is propagated.
+    let mut visited = vec![];
+    let res = table.try_for_each(|k, v| {
+        if v >= 40 {
+            return Err(sled_table::Error::RetryExhausted);
+        }
+        visited.push(k);
+        Ok(())
+    });
+    match res {
+        Err(sled_table::Error::RetryExhausted) => (),
+        res => panic!("unexpected result: {:?}", res),
+    }
+    assert_eq!(visited, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_binary_search_by() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+    for k in (0..30u8).map(|i| i * 3) {
+        table.set(&k, &(k as u64 * k as u64)).unwrap();
+    }
+
+    // Find the first key whose value reaches the threshold.
+    let threshold = 100;
+    let below = table
+        .binary_search_by(|k| match table.get(k).unwrap().unwrap() < threshold {
+            true => cmp::Ordering::Less,
+            false => cmp::Ordering::Greater,
+        })
+        .unwrap();
+    assert_eq!(below, Some((9, 81)));
+    assert_eq!(table.succ(&9).unwrap(), Some((12, 144)));
+
+    // Comparing keys directly is equivalent to `pred_incl` and `pred`.
+    assert_eq!(table.binary_search_by(|k| k.cmp(&42)).unwrap(), Some((42, 42 * 42)));
+    assert_eq!(table.binary_search_by(|k| k.cmp(&43)).unwrap(), table.pred_incl(&43).unwrap());
+    assert_eq!(table.pred(&42).unwrap(), Some((39, 39 * 39)));
+    assert_eq!(table.pred(&0).unwrap(), None);
+    assert_eq!(table.pred_incl(&0).unwrap(), Some((0, 0)));
+}
+
+#[test]
+fn test_iter_from_raw() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<CounterTable>::from(&tree);
+    let other = sled_table::Writer::<MaxTable>::from(&tree);
+    for i in 0..10u8 {
+        table.set(&i, &(i as u64)).unwrap();
+        other.set(&i, &0).unwrap();
+    }
+
+    // Iterate over half of the table and save the cursor.
+    let mut iter = table.iter().unwrap();
+    assert!(iter.cursor().is_none());
+    let first: Vec<_> = iter.by_ref().take(5).map(|res| res.unwrap().0).collect();
+    let cursor = iter.cursor().unwrap().to_vec();
+
+    // Resume after the cursor without leaking into the following table.
+    let rest: Vec<_> = table.iter_from_raw(&cursor).unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(first, (0..5).collect::<Vec<_>>());
+    assert_eq!(rest, (5..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_char_and_bool_keys() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let chars = sled_table::Writer::<CharTable>::from(&tree);
+    let bools = sled_table::Writer::<BoolTable>::from(&tree);
+    assert_eq!(chars.max().unwrap(), None);
+
+    for &c in &['a', 'z', '\u{d7ff}', '\u{e000}', '\u{1f600}'] {
+        chars.set(&c, &()).unwrap();
+        assert_eq!(chars.max().unwrap(), Some((c, ())));
+    }
+    chars.set(&'\u{10ffff}', &()).unwrap();
+    assert_eq!(chars.max().unwrap(), Some(('\u{10ffff}', ())));
+    assert_eq!(chars.pred(&'\u{e000}').unwrap(), Some(('\u{d7ff}', ())));
+    assert_eq!(chars.pred(&'\u{e001}').unwrap(), Some(('\u{e000}', ())));
+    assert_eq!(chars.pred_incl(&'b').unwrap(), Some(('a', ())));
+
+    bools.set(&false, &()).unwrap();
+    assert_eq!(bools.max().unwrap(), Some((false, ())));
+    bools.set(&true, &()).unwrap();
+    assert_eq!(bools.max().unwrap(), Some((true, ())));
+    assert_eq!(bools.pred(&true).unwrap(), Some((false, ())));
+}
+
+#[test]
+fn test_non_zero_keys() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<NonZeroTable, sled_table::MemStore>::from(&store);
+    let key = |u| NonZeroU16::new(u).unwrap();
+    assert_eq!(table.max().unwrap(), None);
+
+    for &u in &[1, 2, 300, std::u16::MAX] {
+        table.set(&key(u), &()).unwrap();
+        assert_eq!(table.max().unwrap(), Some((key(u), ())));
+    }
+    assert_eq!(table.pred(&key(300)).unwrap(), Some((key(2), ())));
+    assert_eq!(table.pred_incl(&key(1)).unwrap(), Some((key(1), ())));
+    assert_eq!(table.pred(&key(1)).unwrap(), None);
+}
+
+#[test]
+fn test_scan_rev() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let byte_table = sled_table::Writer::<ByteTable>::from(&tree);
+    let table = sled_table::Writer::<OtherByteTable>::from(&tree);
+    byte_table.set(&vec![255], &vec![]).unwrap();
+
+    let keys = vec![vec![0], vec![1, 2], vec![1, 255, 0], vec![3], vec![255, 255]];
+    for key in &keys {
+        table.set(key, &key.iter().map(|b| !b).collect()).unwrap();
+    }
+    let collect_keys = |key: &Vec<u8>| -> Vec<Vec<u8>> {
+        table.scan_rev(key).unwrap().map(|res| res.unwrap().0).collect()
+    };
+
+    // Scanning from an existing key includes that key.
+    assert_eq!(collect_keys(&vec![3]), vec![vec![3], vec![1, 255, 0], vec![1, 2], vec![0]]);
+    // Scanning from between keys starts at the predecessor.
+    assert_eq!(collect_keys(&vec![1, 255]), vec![vec![1, 2], vec![0]]);
+    assert_eq!(collect_keys(&vec![2]), vec![vec![1, 255, 0], vec![1, 2], vec![0]]);
+    // Scanning from beyond the last key yields every entry without leaking into other tables.
+    let mut all = keys.clone();
+    all.reverse();
+    assert_eq!(collect_keys(&vec![255, 255, 255]), all);
+    let iter_rev: Vec<_> = table.iter_rev().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(iter_rev, all);
+    assert!(table.scan_rev(&vec![]).unwrap().next().is_none());
+
+    // Values are yielded alongside their keys.
+    let (k, v) = table.scan_rev(&vec![1, 3]).unwrap().next().unwrap().unwrap();
+    assert_eq!((k, v), (vec![1, 2], vec![254, 253]));
+}
+
+#[test]
+fn test_scan_keys_rev() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let below = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    below.set(&vec![255, 255], &vec![]).unwrap();
+    assert!(table.scan_keys_rev(&255).unwrap().next().is_none());
+
+    for k in &[2, 9, 40, 41, 200] {
+        table.set(k, &(*k as u64)).unwrap();
+    }
+    let collect_keys = |key: u8| -> Vec<u8> {
+        table.scan_keys_rev(&key).unwrap().map(Result::unwrap).collect()
+    };
+
+    // Keys are yielded in descending order, ending at the start of the table.
+    assert_eq!(collect_keys(41), vec![41, 40, 9, 2]);
+    assert_eq!(collect_keys(39), vec![9, 2]);
+    assert_eq!(collect_keys(255), vec![200, 41, 40, 9, 2]);
+    assert!(collect_keys(1).is_empty());
+
+    // A page of the previous keys matches the keys of `scan_rev`.
+    let page: Vec<_> = table.scan_keys_rev(&199).unwrap().take(2).map(Result::unwrap).collect();
+    let entries = table.scan_rev(&199).unwrap().take(2).map(Result::unwrap);
+    assert_eq!(page, entries.map(|(k, _)| k).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_scan_raw() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<GroupTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    other.set(&0, &0).unwrap();
+    let keys = vec![(1, 1), (1, 5), (2, 0), (2, 3), (256, 2)];
+    for (i, key) in keys.iter().enumerate() {
+        table.set(key, &(i as u32)).unwrap();
+    }
+
+    // Scanning from the serialized key without its table ID matches `scan`.
+    let id_len = mem::size_of::<<GroupTable as Table>::Id>();
+    for key in &[(0, 0), (1, 5), (2, 1), (256, 2), (300, 0)] {
+        let key_bytes = sled_table::write_key::<GroupTable>(key).unwrap();
+        let raw = table.scan_raw(&key_bytes[id_len..]).unwrap();
+        let raw: Vec<_> = raw.map(Result::unwrap).collect();
+        let scanned: Vec<_> = table.scan(key).unwrap().map(Result::unwrap).collect();
+        assert_eq!(raw, scanned);
+    }
+}
+
+#[test]
+fn test_iter_decode_errors() {
+    use sled_table::Store;
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<GroupTable, sled_table::MemStore>::from(&store);
+    table.set(&(1, 1), &0).unwrap();
+
+    // A truncated key is reported along with its bytes.
+    let id_bytes = sled_table::write_key::<GroupTable>(&(0, 0)).unwrap()[..1].to_vec();
+    let mut truncated = id_bytes.clone();
+    truncated.extend(&[0, 1, 0]);
+    store.set(truncated.clone(), table.get_raw(&(1, 1)).unwrap().unwrap()).unwrap();
+    match table.iter().unwrap().next() {
+        Some(Err(sled_table::Error::DecodeKey { id_bytes: ref id, ref key_bytes, ref source })) => {
+            assert_eq!(*id, id_bytes);
+            assert_eq!(*key_bytes, vec![0, 1, 0]);
+            assert!(source.as_bytekey().is_some());
+        }
+        other => panic!("expected a key decoding error, found {:?}", other),
+    }
+
+    // As is a truncated value, along with the key at which it is stored.
+    store.del(&truncated).unwrap();
+    table.set_raw(&(1, 1), &[7]).unwrap();
+    match table.iter().unwrap().next() {
+        Some(Err(sled_table::Error::DecodeValue { ref key_bytes, ref value_bytes, .. })) => {
+            let expected = sled_table::write_key::<GroupTable>(&(1, 1)).unwrap();
+            assert_eq!(*key_bytes, &expected[1..]);
+            assert_eq!(*value_bytes, vec![7]);
+        }
+        other => panic!("expected a value decoding error, found {:?}", other),
+    }
+    assert!(table.iter_rev().unwrap().next().unwrap().unwrap_err().is_corruption());
+}
+
+#[test]
+fn test_cached_id_prefix() {
+    let store = sled_table::MemStore::new();
+    let below = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let table = sled_table::Writer::<OtherByteTable, sled_table::MemStore>::from(&store);
+    let above = sled_table::Writer::<GroupTable, sled_table::MemStore>::from(&store);
+    below.set(&vec![255, 255], &vec![]).unwrap();
+    above.set(&(0, 0), &0).unwrap();
+    for key in vec![vec![0], vec![1, 2], vec![255]] {
+        table.set(&key, &key).unwrap();
+    }
+
+    // Readers that share the cached prefix by cloning or conversion stop at the table boundary.
+    let cloned = table.clone();
+    let reader = sled_table::Reader::from(table.clone());
+    let expected = vec![vec![0], vec![1, 2], vec![255]];
+    for reader in &[&*cloned, &reader] {
+        let keys: Vec<_> = reader.iter().unwrap().map(|res| res.unwrap().0).collect();
+        assert_eq!(keys, expected);
+        let rev: Vec<_> = reader.iter_rev().unwrap().map(|res| res.unwrap().0).collect();
+        assert_eq!(rev, expected.iter().rev().cloned().collect::<Vec<_>>());
+        assert_eq!(reader.scan(&vec![2]).unwrap().count(), 1);
+        assert_eq!(reader.scan_prefix_bytes(&()).unwrap().count(), 3);
+    }
+}
+
+#[test]
+fn test_scan_prefix() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<GroupTable>::from(&tree);
+    let keys = vec![(1, 1), (1, 5), (2, 0), (2, 3), (2, 65535), (3, 0), (256, 2)];
+    for (i, key) in keys.iter().enumerate() {
+        table.set(key, &(i as u32)).unwrap();
+    }
+
+    let group = |g: u16| -> Vec<((u16, u16), u32)> {
+        table.scan_prefix(&g).unwrap().map(Result::unwrap).collect()
+    };
+    assert_eq!(group(2), vec![((2, 0), 2), ((2, 3), 3), ((2, 65535), 4)]);
+    assert_eq!(group(1), vec![((1, 1), 0), ((1, 5), 1)]);
+    assert_eq!(group(256), vec![((256, 2), 6)]);
+    assert!(group(0).is_empty());
+    assert!(group(4).is_empty());
+
+    // The full key may also be used as a prefix.
+    assert_eq!(table.scan_prefix(&(2u16, 3u16)).unwrap().count(), 1);
+}
+
+#[test]
+fn test_raw_prefix_iter() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<GroupTable>::from(&tree);
+    let next_table = sled_table::Writer::<CounterTable>::from(&tree);
+    let keys = vec![(1, 1), (1, 5), (2, 0), (2, 3), (2, 65535), (258, 0), (65535, 9)];
+    for (i, key) in keys.iter().enumerate() {
+        table.set(key, &(i as u32)).unwrap();
+    }
+    next_table.set(&0, &0).unwrap();
+
+    let raw_prefix = |prefix: &[u8]| -> Vec<(u16, u16)> {
+        table.raw_prefix_iter(prefix).unwrap().map(|res| res.unwrap().0).collect()
+    };
+    // The leading byte of each `u16` group.
+    assert_eq!(raw_prefix(&[0]), vec![(1, 1), (1, 5), (2, 0), (2, 3), (2, 65535)]);
+    assert_eq!(raw_prefix(&[1]), vec![(258, 0)]);
+    // A prefix spanning into the second field.
+    assert_eq!(raw_prefix(&[0, 2, 0]), vec![(2, 0), (2, 3)]);
+    assert_eq!(raw_prefix(&[]), keys);
+    assert!(raw_prefix(&[3]).is_empty());
+}
+
+#[test]
+fn test_count_prefix() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<GroupTable>::from(&tree);
+    let next_table = sled_table::Writer::<CounterTable>::from(&tree);
+    let keys = vec![(1, 1), (1, 5), (2, 0), (2, 3), (2, 65535), (65535, 0), (65535, 9)];
+    for (i, key) in keys.iter().enumerate() {
+        table.set(key, &(i as u32)).unwrap();
+    }
+    next_table.set(&0, &0).unwrap();
+
+    assert_eq!(table.count_prefix(&2u16).unwrap(), 3);
+    assert_eq!(table.count_prefix(&1u16).unwrap(), 2);
+    assert_eq!(table.count_prefix(&65535u16).unwrap(), 2);
+    assert_eq!(table.count_prefix(&3u16).unwrap(), 0);
+}
+
 #[test]
 fn test_table_size_bytes() {
     let config = sled::ConfigBuilder::new().temporary(true).build();
@@ -94,3 +1400,398 @@ fn test_table_size_bytes() {
     assert_eq!(table.size_bytes().unwrap(), expected_a);
     assert_eq!(table.size_bytes().unwrap(), sled_table::tree_size_bytes(&tree).unwrap());
 }
+
+#[test]
+fn test_entry_size() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    let a_key = vec![1, 2, 3, 4];
+    let a_value = vec![5, 6, 7, 8];
+    assert_eq!(table.entry_size(&a_key).unwrap(), None);
+
+    table.set(&a_key, &a_value).unwrap();
+    table.set(&vec![1, 2, 3, 0], &vec![0]).unwrap();
+    let expected_a = mem::size_of::<<ByteTable as Table>::Id>() // a_key prepended table ID
+        + a_key.len()
+        + mem::size_of::<usize>() // length of a_value
+        + a_value.len();
+    assert_eq!(table.entry_size(&a_key).unwrap(), Some(expected_a));
+    let total = table.entry_size(&a_key).unwrap().unwrap()
+        + table.entry_size(&vec![1, 2, 3, 0]).unwrap().unwrap();
+    assert_eq!(total, table.size_bytes().unwrap());
+}
+
+#[test]
+fn test_stats() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<OtherByteTable, sled_table::MemStore>::from(&store);
+    other.set(&vec![0], &vec![0; 16]).unwrap();
+    let stats = table.stats().unwrap();
+    assert_eq!((stats.count, stats.size_bytes), (0, 0));
+    assert_eq!((stats.first_key, stats.last_key), (None, None));
+
+    for key in vec![vec![3, 1], vec![1], vec![7, 7, 7], vec![2]] {
+        let value = vec![9; key.len()];
+        table.set(&key, &value).unwrap();
+    }
+    let stats = table.stats().unwrap();
+    assert_eq!(stats.count, table.iter().unwrap().count());
+    assert_eq!(stats.size_bytes, table.size_bytes().unwrap());
+    assert_eq!(stats.first_key, table.min_key().unwrap());
+    assert_eq!(stats.last_key, table.iter_rev().unwrap().next().map(|res| res.unwrap().0));
+    assert_eq!(stats.first_key, Some(vec![1]));
+    assert_eq!(stats.last_key, Some(vec![7, 7, 7]));
+}
+
+#[test]
+fn test_table_value_bytes() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    let other = sled_table::Writer::<OtherByteTable>::from(&tree);
+    other.set(&vec![0], &vec![0; 16]).unwrap();
+    assert_eq!(table.value_bytes().unwrap(), 0);
+
+    let a_value = vec![5, 6, 7, 8];
+    let b_value = vec![0];
+    table.set(&vec![1, 2, 3, 4], &a_value).unwrap();
+    table.set(&vec![1, 2, 3, 0], &b_value).unwrap();
+    let expected = mem::size_of::<usize>() // length of a_value
+        + a_value.len()
+        + mem::size_of::<usize>() // length of b_value
+        + b_value.len();
+    assert_eq!(table.value_bytes().unwrap(), expected);
+}
+
+#[test]
+fn test_clear_tree() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let byte_table = sled_table::Writer::<ByteTable>::from(&tree);
+    let counter_table = sled_table::Writer::<CounterTable>::from(&tree);
+    for i in 0..10u8 {
+        byte_table.set(&vec![i], &vec![i]).unwrap();
+        counter_table.set(&i, &(i as u64)).unwrap();
+    }
+
+    sled_table::clear_tree(&tree).unwrap();
+    assert!(byte_table.iter().unwrap().next().is_none());
+    assert!(counter_table.iter().unwrap().next().is_none());
+    assert_eq!(sled_table::tree_size_bytes(&tree).unwrap(), 0);
+}
+
+#[test]
+fn test_clear_tree_mem_store() {
+    let store = sled_table::MemStore::new();
+    let byte_table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let counter_table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    for i in 0..10u8 {
+        byte_table.set(&vec![i], &vec![i]).unwrap();
+        counter_table.set(&i, &(i as u64)).unwrap();
+    }
+
+    sled_table::clear_tree(&store).unwrap();
+    assert!(byte_table.iter().unwrap().next().is_none());
+    assert!(counter_table.iter().unwrap().next().is_none());
+}
+
+#[test]
+fn test_table_summary() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    assert!(sled_table::table_summary::<u8, _>(&tree).unwrap().is_empty());
+    let byte_table = sled_table::Writer::<ByteTable>::from(&tree);
+    let other_byte_table = sled_table::Writer::<OtherByteTable>::from(&tree);
+    let counter_table = sled_table::Writer::<CounterTable>::from(&tree);
+    for i in 0..3u8 {
+        byte_table.set(&vec![i], &vec![]).unwrap();
+    }
+    other_byte_table.set(&vec![], &vec![]).unwrap();
+    for i in 0..5u8 {
+        counter_table.set(&i, &0).unwrap();
+    }
+
+    let summary = sled_table::table_summary::<u8, _>(&tree).unwrap();
+    assert_eq!(summary, vec![(0, 3), (2, 1), (4, 5)]);
+}
+
+#[test]
+fn test_table_summary_mem_store() {
+    let store = sled_table::MemStore::new();
+    let byte_table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let group_table = sled_table::Writer::<GroupTable, sled_table::MemStore>::from(&store);
+    byte_table.set(&vec![1; 64], &vec![]).unwrap();
+    for i in 0..4 {
+        group_table.set(&(i, i), &0).unwrap();
+    }
+    let summary = sled_table::table_summary::<u8, _>(&store).unwrap();
+    assert_eq!(summary, vec![(0, 1), (3, 4)]);
+}
+
+#[test]
+fn test_estimate_len() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<ByteTable>::from(&tree);
+    assert_eq!(table.estimate_len().unwrap(), 0);
+    for i in 0..4u8 {
+        table.set(&vec![i], &vec![i; 8]).unwrap();
+    }
+    assert_eq!(table.estimate_len().unwrap(), 4);
+
+    // Entries of a uniform size are estimated within a small tolerance.
+    for i in 0..1000u16 {
+        let key = vec![(i >> 8) as u8, i as u8];
+        table.set(&key, &vec![0; 8]).unwrap();
+    }
+    let len = table.iter().unwrap().count();
+    let estimate = table.estimate_len().unwrap();
+    assert!(estimate >= len * 9 / 10 && estimate <= len * 11 / 10);
+}
+
+#[test]
+fn test_versioned_table() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+
+    // Write a value under the first version of the schema.
+    {
+        let v1 = sled_table::Writer::<PointTableV1>::from(&tree);
+        v1.set(&0, &PointV1 { x: 42 }).unwrap();
+        assert_eq!(v1.get(&0).unwrap().unwrap(), PointV1 { x: 42 });
+    }
+
+    // Reading under the second version migrates the old value.
+    let v2 = sled_table::Writer::<PointTableV2>::from(&tree);
+    assert_eq!(v2.get(&0).unwrap().unwrap(), PointV2 { x: 42, y: 0 });
+    assert_eq!(v2.iter().unwrap().next().unwrap().unwrap(), (0, PointV2 { x: 42, y: 0 }));
+
+    // Values written under the second version are read directly.
+    v2.set(&0, &PointV2 { x: 1, y: 2 }).unwrap();
+    assert_eq!(v2.get(&0).unwrap().unwrap(), PointV2 { x: 1, y: 2 });
+}
+
+#[test]
+fn test_stored_version() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    {
+        let v1 = sled_table::Writer::<PointTableV1>::from(&tree);
+        v1.set(&0, &PointV1 { x: 1 }).unwrap();
+        v1.set(&1, &PointV1 { x: 2 }).unwrap();
+    }
+    let v2 = sled_table::Writer::<PointTableV2>::from(&tree);
+    v2.set(&2, &PointV2 { x: 3, y: 4 }).unwrap();
+
+    let versions: Vec<_> = (0..4).map(|k| v2.stored_version(&k).unwrap()).collect();
+    assert_eq!(versions, vec![Some(1), Some(1), Some(2), None]);
+
+    // Unversioned tables have no stored version.
+    let bytes = sled_table::Writer::<ByteTable>::from(&tree);
+    bytes.set(&vec![0], &vec![1]).unwrap();
+    assert_eq!(bytes.stored_version(&vec![0]).unwrap(), None);
+}
+
+#[test]
+fn test_copy_to() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let src_tree = sled::Tree::start(config).unwrap();
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let dst_tree = sled::Tree::start(config).unwrap();
+    let src = sled_table::Writer::<ByteTable>::from(&src_tree);
+    let src_other = sled_table::Writer::<OtherByteTable>::from(&src_tree);
+    let dst = sled_table::Writer::<ByteTable>::from(&dst_tree);
+    let dst_other = sled_table::Writer::<OtherByteTable>::from(&dst_tree);
+    for i in 0..10u8 {
+        src.set(&vec![i], &vec![i; i as usize]).unwrap();
+    }
+    src_other.set(&vec![0], &vec![0]).unwrap();
+
+    assert_eq!(src.copy_to(&dst).unwrap(), 10);
+    let src_entries: Vec<_> = src.iter().unwrap().map(Result::unwrap).collect();
+    let dst_entries: Vec<_> = dst.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(src_entries, dst_entries);
+
+    // Other tables within the source tree are not copied.
+    assert!(dst_other.iter().unwrap().next().is_none());
+}
+
+#[test]
+fn test_diff() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree_a = sled::Tree::start(config).unwrap();
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree_b = sled::Tree::start(config).unwrap();
+    let a = sled_table::Writer::<CounterTable>::from(&tree_a);
+    let b = sled_table::Writer::<CounterTable>::from(&tree_b);
+    for k in 0..5 {
+        a.set(&k, &(k as u64)).unwrap();
+        b.set(&k, &(k as u64)).unwrap();
+    }
+    assert!(a.diff(&b).unwrap().is_empty());
+
+    b.set(&7, &7).unwrap();
+    b.del(&0).unwrap();
+    b.set(&3, &30).unwrap();
+    let expected = vec![
+        sled_table::Diff::Removed(0, 0),
+        sled_table::Diff::Changed(3, 3, 30),
+        sled_table::Diff::Added(7, 7),
+    ];
+    assert_eq!(a.diff(&b).unwrap(), expected);
+}
+
+#[test]
+fn test_key_range() {
+    let config = sled::ConfigBuilder::new().temporary(true).build();
+    let tree = sled::Tree::start(config).unwrap();
+    let table = sled_table::Writer::<GroupTable>::from(&tree);
+    let prev = sled_table::Writer::<OtherByteTable>::from(&tree);
+    let next = sled_table::Writer::<CounterTable>::from(&tree);
+    for &k in &[(0, 0), (0, 255), (255, 0), (65_535, 65_535)] {
+        table.set(&k, &0).unwrap();
+    }
+    prev.set(&vec![255; 4], &vec![]).unwrap();
+    next.set(&0, &0).unwrap();
+
+    // Every key of the table falls within the range while no other table's keys do.
+    let (start, end) = table.key_range().unwrap();
+    let end = end.unwrap();
+    let within = |k: &[u8]| start.as_slice() <= k && k < end.as_slice();
+    for res in table.iter_bytes().unwrap() {
+        assert!(within(&res.unwrap().0));
+    }
+    for res in prev.iter_bytes().unwrap().chain(next.iter_bytes().unwrap()) {
+        assert!(!within(&res.unwrap().0));
+    }
+    let count = tree.iter().filter(|res| within(&res.as_ref().unwrap().0)).count();
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn test_fold_raw() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<OtherByteTable, sled_table::MemStore>::from(&store);
+    for i in 0..10u8 {
+        table.set(&vec![i; i as usize], &vec![i; 10 - i as usize]).unwrap();
+    }
+    other.set(&vec![0], &vec![0; 100]).unwrap();
+
+    let len = table.fold_raw(0, |acc, k, v| acc + k.len() + v.len()).unwrap();
+    assert_eq!(len, table.size_bytes().unwrap());
+    assert_eq!(table.fold_raw(0, |acc, _, _| acc + 1).unwrap(), 10);
+}
+
+#[test]
+fn test_iter_chunked() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<CounterTable, sled_table::MemStore>::from(&store);
+    let other = sled_table::Writer::<MaxTable, sled_table::MemStore>::from(&store);
+    for k in 0..10 {
+        table.set(&k, &(k as u64)).unwrap();
+    }
+    other.set(&0, &0).unwrap();
+
+    let chunks: Vec<_> = table.iter_chunked(4).unwrap().map(Result::unwrap).collect();
+    let sizes: Vec<_> = chunks.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![4, 4, 2]);
+    let entries: Vec<_> = chunks.into_iter().flat_map(|chunk| chunk).collect();
+    let expected: Vec<_> = table.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(entries, expected);
+    assert_eq!(table.iter_chunked(10).unwrap().count(), 1);
+    assert_eq!(table.iter_chunked(11).unwrap().next().unwrap().unwrap().len(), 10);
+}
+
+#[test]
+fn test_bincode_config() {
+    let store = sled_table::MemStore::new();
+    let table = sled_table::Writer::<LimitedTable, sled_table::MemStore>::from(&store);
+    table.set(&0, &vec![1; 32]).unwrap();
+    assert_eq!(table.get(&0).unwrap().unwrap(), vec![1; 32]);
+
+    fn is_size_limit(err: sled_table::Error) -> bool {
+        match err.as_bincode().map(|err| &**err) {
+            Some(&bincode::ErrorKind::SizeLimit) => true,
+            _ => false,
+        }
+    }
+
+    // Values exceeding the limit cannot be written, which does not indicate corruption.
+    let err = table.set(&1, &vec![1; 100]).unwrap_err();
+    assert!(!err.is_corruption());
+    assert!(is_size_limit(err));
+    assert!(table.get(&1).unwrap().is_none());
+}
+
+#[test]
+fn test_mem_store() {
+    let store = sled_table::MemStore::new();
+    let byte_table = sled_table::Writer::<ByteTable, sled_table::MemStore>::from(&store);
+    let table = sled_table::Writer::<OtherByteTable, sled_table::MemStore>::from(&store);
+    byte_table.set(&vec![255], &vec![]).unwrap();
+
+    // Writer::set/get/del
+    let keys = vec![vec![0], vec![1, 2], vec![1, 255, 0], vec![3]];
+    for key in &keys {
+        table.set(key, key).unwrap();
+    }
+    assert_eq!(table.get(&vec![1, 2]).unwrap().unwrap(), vec![1, 2]);
+    assert_eq!(table.del(&vec![3]).unwrap().unwrap(), vec![3]);
+    assert!(table.get(&vec![3]).unwrap().is_none());
+
+    // Iteration is ordered and limited to the table.
+    let collected: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(collected, &keys[..3]);
+    let collected: Vec<_> = table.scan_rev(&vec![2]).unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(collected, vec![vec![1, 255, 0], vec![1, 2], vec![0]]);
+
+    // Writer::swap
+    assert_eq!(table.swap(&vec![0], &vec![9]).unwrap().unwrap(), vec![0]);
+    assert_eq!(table.get(&vec![0]).unwrap().unwrap(), vec![9]);
+}
+
+#[cfg(all(debug_assertions, feature = "registry"))]
+#[test]
+fn test_table_id_collision() {
+    fn collision_msg<F: FnOnce()>(f: F) -> String {
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+        let payload = res.unwrap_err();
+        payload.downcast_ref::<String>().unwrap().clone()
+    }
+
+    let store = sled_table::MemStore::new();
+    let a = sled_table::Reader::<CollidingTableA, sled_table::MemStore>::from(&store);
+    let _a = sled_table::Writer::<CollidingTableA, sled_table::MemStore>::from(&store);
+    let _a = a.clone();
+    let msg = collision_msg(|| {
+        let _b = sled_table::Reader::<CollidingTableB, sled_table::MemStore>::from(&store);
+    });
+    assert!(msg.contains("CollidingTableA"));
+    assert!(msg.contains("CollidingTableB"));
+
+    // IDs whose serialized bytes are a prefix of one another also collide.
+    let msg = collision_msg(|| {
+        let _c = sled_table::Reader::<CollidingTableC, sled_table::MemStore>::from(&store);
+    });
+    assert!(msg.contains("CollidingTableA"));
+    assert!(msg.contains("CollidingTableC"));
+
+    // Tables within other stores do not collide.
+    let other_store = sled_table::MemStore::new();
+    let _b = sled_table::Reader::<CollidingTableB, sled_table::MemStore>::from(&other_store);
+}
+
+#[cfg(all(debug_assertions, feature = "registry"))]
+#[test]
+fn test_table_id_released_on_drop() {
+    let store = sled_table::MemStore::new();
+    {
+        let a = sled_table::Writer::<CollidingTableA, sled_table::MemStore>::from(&store);
+        let _a = a.clone();
+    }
+    // Once every reader of the first table is dropped, its ID may be used by another.
+    let _b = sled_table::Reader::<CollidingTableB, sled_table::MemStore>::from(&store);
+}
@@ -4,8 +4,12 @@ extern crate serde_derive;
 extern crate sled;
 extern crate sled_table;
 
-use sled_table::Table;
+use sled_table::{Table, Timestamp};
 use sled_table::timestamp::Key;
+use std::ops;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
 
 // A unix timestamp representation in nanoseconds.
 //
@@ -30,6 +34,26 @@ impl sled_table::unsigned_binary_search::UnsignedBinarySearchKey for UnixNanos {
     }
 }
 
+impl ops::Sub for UnixNanos {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        UnixNanos(self.0 - other.0)
+    }
+}
+
+impl sled_table::timestamp::CheckedSub for UnixNanos {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(UnixNanos)
+    }
+}
+
+impl ops::Rem for UnixNanos {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        UnixNanos(self.0 % other.0)
+    }
+}
+
 impl sled_table::timestamp::MinKey for UnixNanos {
     fn min_key() -> Self {
         UnixNanos(::std::i64::MIN)
@@ -37,8 +61,8 @@ impl sled_table::timestamp::MinKey for UnixNanos {
 }
 
 impl sled_table::Timestamp for UnixNanos {
-    fn next(&self) -> Self {
-        UnixNanos(self.0.checked_add(1).expect("no timestamps left within i64 range"))
+    fn checked_next(&self) -> Option<Self> {
+        self.0.checked_add(1).map(UnixNanos)
     }
 }
 
@@ -49,6 +73,9 @@ impl sled_table::Timestamp for UnixNanos {
 enum TableId {
     Foo = 0,
     FooTimestamp = 1,
+    Note = 2,
+    NoteTimestamp = 3,
+    NoteKeyTimestamp = 4,
 }
 
 #[test]
@@ -82,6 +109,7 @@ impl Table for FooTable {
     type Id = TableId;
     type Key = FooId;
     type Value = Foo;
+    type Codec = sled_table::Bincode;
     const ID: Self::Id = TableId::Foo;
 }
 
@@ -89,6 +117,7 @@ impl Table for FooTimestampTable {
     type Id = TableId;
     type Key = Key<UnixNanos, <FooTable as Table>::Key>;
     type Value = ();
+    type Codec = sled_table::Bincode;
     const ID: Self::Id = TableId::FooTimestamp;
 }
 
@@ -100,6 +129,55 @@ impl sled_table::Timestamped for FooTable {
     }
 }
 
+impl sled_table::SetTimestamp for FooTable {
+    fn set_value_timestamp(value: &mut Self::Value, timestamp: UnixNanos) {
+        value.timestamp = timestamp;
+    }
+}
+
+impl sled_table::Ttl for FooTable {
+    const TTL: UnixNanos = UnixNanos(10);
+}
+
+// The table of plain notes, timestamped explicitly.
+struct NoteTable;
+
+// The table keyed by the timestamp supplied for each note.
+struct NoteTimestampTable;
+
+// The table mapping each note's key to the timestamp supplied for it.
+struct NoteKeyTimestampTable;
+
+impl Table for NoteTable {
+    type Id = TableId;
+    type Key = u8;
+    type Value = String;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = TableId::Note;
+}
+
+impl Table for NoteTimestampTable {
+    type Id = TableId;
+    type Key = Key<UnixNanos, <NoteTable as Table>::Key>;
+    type Value = ();
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = TableId::NoteTimestamp;
+}
+
+impl Table for NoteKeyTimestampTable {
+    type Id = TableId;
+    type Key = <NoteTable as Table>::Key;
+    type Value = UnixNanos;
+    type Codec = sled_table::Bincode;
+    const ID: Self::Id = TableId::NoteKeyTimestamp;
+}
+
+impl sled_table::TimestampedExplicit for NoteTable {
+    type Timestamp = UnixNanos;
+    type TimestampTable = NoteTimestampTable;
+    type KeyTimestampTable = NoteKeyTimestampTable;
+}
+
 // A temporary tree for testing.
 fn test_tree() -> sled::Tree {
     let config = sled::ConfigBuilder::new().temporary(true).build();
@@ -169,3 +247,672 @@ fn test_timestamp_table() {
     assert_eq!(table.del(&b_key).unwrap().unwrap(), b_value);
     assert_eq!(table.del(&b_key).unwrap(), None);
 }
+
+#[test]
+fn test_timestamp_table_update() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let a_value = Foo { timestamp: UnixNanos(2), data: vec![0] };
+    let b_value = Foo { timestamp: UnixNanos(4), data: vec![1] };
+    table.set(&0, &a_value).unwrap();
+    table.set(&1, &b_value).unwrap();
+
+    // Move `a` after `b` in time.
+    let a_value = Foo { timestamp: UnixNanos(6), data: vec![2] };
+    table.set(&0, &a_value).unwrap();
+
+    // Both the key-ordered and the time-ordered views reflect the update.
+    let by_key: Vec<_> = table.by_key().iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(by_key, vec![(0, a_value.clone()), (1, b_value.clone())]);
+    let by_time: Vec<_> = table.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(by_time, vec![(1, b_value.clone()), (0, a_value.clone())]);
+    assert_eq!(table.min().unwrap(), Some(UnixNanos(4)));
+
+    // The stale timestamp entry was removed from the index.
+    let index = sled_table::Reader::<FooTimestampTable>::from(&t);
+    let index_keys: Vec<_> = index.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(index_keys, vec![Key::from((UnixNanos(4), 1)), Key::from((UnixNanos(6), 0))]);
+}
+
+#[test]
+fn test_touch() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    for (key, &ts) in [2, 4, 6].iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // Move the oldest entry to the end, keeping its data.
+    assert!(table.touch(&0, UnixNanos(8)).unwrap());
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(by_time, vec![1, 2, 0]);
+    assert_eq!(table.get(&0).unwrap().unwrap(), Foo { timestamp: UnixNanos(8), data: vec![0] });
+
+    // Touching with the same timestamp or a missing key changes nothing.
+    assert!(table.touch(&0, UnixNanos(8)).unwrap());
+    assert!(!table.touch(&3, UnixNanos(0)).unwrap());
+    assert!(table.get(&3).unwrap().is_none());
+
+    // The stale timestamp entry was removed from the index.
+    let index = sled_table::Reader::<FooTimestampTable>::from(&t);
+    let index_keys: Vec<_> = index.iter().unwrap().map(|res| res.unwrap().0).collect();
+    let expected = vec![(4, 1), (6, 2), (8, 0)]
+        .into_iter()
+        .map(|(ts, k)| Key::from((UnixNanos(ts), k)))
+        .collect::<Vec<_>>();
+    assert_eq!(index_keys, expected);
+}
+
+#[test]
+fn test_rename_key() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    for (key, &ts) in [2, 4, 6].iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // Rename to a new key, then over an existing key.
+    assert!(table.rename_key(&0, &5).unwrap());
+    assert!(table.rename_key(&1, &2).unwrap());
+    assert!(!table.rename_key(&0, &7).unwrap());
+    assert!(table.get(&0).unwrap().is_none());
+    assert!(table.get(&1).unwrap().is_none());
+    assert_eq!(table.get(&5).unwrap().unwrap(), Foo { timestamp: UnixNanos(2), data: vec![0] });
+    assert_eq!(table.get(&2).unwrap().unwrap(), Foo { timestamp: UnixNanos(4), data: vec![1] });
+
+    // Both the key-ordered and time-ordered views reflect the new keys.
+    let by_key = sled_table::Reader::<FooTable>::from(&t);
+    let keys: Vec<_> = by_key.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(keys, vec![2, 5]);
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(by_time, vec![5, 2]);
+    let index = sled_table::Reader::<FooTimestampTable>::from(&t);
+    let index_keys: Vec<_> = index.iter().unwrap().map(|res| res.unwrap().0).collect();
+    let expected = vec![(2, 5), (4, 2)]
+        .into_iter()
+        .map(|(ts, k)| Key::from((UnixNanos(ts), k)))
+        .collect::<Vec<_>>();
+    assert_eq!(index_keys, expected);
+}
+
+#[test]
+fn test_rename_key_conflicts() {
+    let t = Arc::new(test_tree());
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&*t);
+    table.set(&0, &Foo { timestamp: UnixNanos(0), data: vec![] }).unwrap();
+
+    // Setting `1` while it is renamed back and forth forces the compare-and-swaps to conflict.
+    let done = Arc::new(AtomicBool::new(false));
+    let setter = {
+        let (t, done) = (t.clone(), done.clone());
+        thread::spawn(move || {
+            let table = sled_table::timestamp::Writer::<FooTable>::from(&*t);
+            let mut ts = 1;
+            while !done.load(Ordering::SeqCst) {
+                table.set(&1, &Foo { timestamp: UnixNanos(ts), data: vec![] }).unwrap();
+                ts += 1;
+            }
+        })
+    };
+    for _ in 0..2_000 {
+        table.rename_key(&0, &1).unwrap();
+        table.rename_key(&1, &0).unwrap();
+    }
+    done.store(true, Ordering::SeqCst);
+    setter.join().unwrap();
+
+    // Every timestamp entry must belong to a value within the table.
+    let index = sled_table::Reader::<FooTimestampTable>::from(&*t);
+    let index_keys: Vec<_> = index.iter().unwrap().map(|res| res.unwrap().0).collect();
+    let mut expected: Vec<_> = table
+        .by_key()
+        .iter()
+        .unwrap()
+        .map(|res| res.unwrap())
+        .map(|(k, v)| Key::from((v.timestamp, k)))
+        .collect();
+    expected.sort();
+    assert_eq!(index_keys, expected);
+    assert_eq!(table.count().unwrap(), expected.len());
+}
+
+#[test]
+fn test_timestamped_explicit() {
+    let t = test_tree();
+    let table = sled_table::timestamp::ExplicitWriter::<NoteTable>::from(&t);
+    let notes = vec![(0, 30, "c"), (1, 10, "a"), (2, 20, "b")];
+    for &(key, ts, note) in &notes {
+        table.set(&key, UnixNanos(ts), &note.to_string()).unwrap();
+    }
+    assert_eq!(table.get(&1).unwrap(), Some("a".to_string()));
+    assert_eq!(table.timestamp(&1).unwrap(), Some(UnixNanos(10)));
+    assert_eq!(table.get(&3).unwrap(), None);
+
+    // Entries are scanned in the order of their supplied timestamps.
+    let by_time: Vec<_> = table.iter().unwrap()
+        .map(|res| res.unwrap())
+        .map(|(tk, note)| (tk.key, tk.timestamp.0, note))
+        .collect();
+    let expected = vec![(1, 10, "a".to_string()), (2, 20, "b".into()), (0, 30, "c".into())];
+    assert_eq!(by_time, expected);
+    let from_20: Vec<_> = table.scan(UnixNanos(20)).unwrap()
+        .map(|res| res.unwrap().0.key)
+        .collect();
+    assert_eq!(from_20, vec![2, 0]);
+
+    // Re-setting a key with a new timestamp moves it within the chronological order.
+    table.set(&1, UnixNanos(40), &"d".to_string()).unwrap();
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0.key).collect();
+    assert_eq!(by_time, vec![2, 0, 1]);
+    assert_eq!(table.get(&1).unwrap(), Some("d".to_string()));
+    let index = sled_table::Reader::<NoteTimestampTable>::from(&t);
+    assert_eq!(index.iter().unwrap().count(), 3);
+
+    // Removing a key removes its timestamped entry.
+    assert_eq!(table.del(&2).unwrap(), Some("b".to_string()));
+    assert_eq!(table.del(&2).unwrap(), None);
+    assert_eq!(table.get(&2).unwrap(), None);
+    assert_eq!(index.iter().unwrap().count(), 2);
+
+    // Timestamp entries that do not match the timestamp of their key are skipped.
+    let index = sled_table::Writer::<NoteTimestampTable>::from(&t);
+    index.set(&Key { timestamp: UnixNanos(5), key: 0 }, &()).unwrap();
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0.key).collect();
+    assert_eq!(by_time, vec![0, 1]);
+}
+
+#[test]
+fn test_timestamped_explicit_mem_store() {
+    let store = sled_table::MemStore::new();
+    let table =
+        sled_table::timestamp::ExplicitWriter::<NoteTable, sled_table::MemStore>::from(&store);
+    table.set(&0, UnixNanos(20), &"b".to_string()).unwrap();
+    table.set(&1, UnixNanos(10), &"a".to_string()).unwrap();
+    assert_eq!(table.get(&0).unwrap(), Some("b".to_string()));
+    let by_time: Vec<_> = table.iter().unwrap()
+        .map(|res| res.unwrap())
+        .map(|(tk, note)| (tk.key, tk.timestamp.0, note))
+        .collect();
+    assert_eq!(by_time, vec![(1, 10, "a".to_string()), (0, 20, "b".to_string())]);
+}
+
+#[test]
+fn test_age_since() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let value = Foo { timestamp: UnixNanos(40), data: vec![] };
+    table.set(&0, &value).unwrap();
+
+    assert_eq!(table.age_since(&0, UnixNanos(100)).unwrap(), Some(UnixNanos(60)));
+    assert_eq!(table.age_since(&0, UnixNanos(40)).unwrap(), Some(UnixNanos(0)));
+    assert_eq!(table.age_since(&1, UnixNanos(100)).unwrap(), None);
+
+    // A `now` preceding the entry yields a negative age, or `None` if the age would overflow.
+    assert_eq!(table.age_since(&0, UnixNanos(30)).unwrap(), Some(UnixNanos(-10)));
+    assert_eq!(table.age_since(&0, UnixNanos(::std::i64::MIN)).unwrap(), None);
+}
+
+#[test]
+fn test_gc_older_than() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 1, 7];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // Only the entries strictly older than the cutoff are removed.
+    assert_eq!(table.gc_older_than(UnixNanos(5)).unwrap(), 2);
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(by_time, vec![2, 4, 0]);
+    assert!(table.get(&1).unwrap().is_none());
+    assert!(table.get(&3).unwrap().is_none());
+
+    // The timestamp index is kept consistent with the table.
+    let index = sled_table::Reader::<FooTimestampTable>::from(&t);
+    assert_eq!(index.iter().unwrap().count(), 3);
+    assert_eq!(table.gc_older_than(UnixNanos(5)).unwrap(), 0);
+}
+
+#[test]
+fn test_expire() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![12, 3, 25, 15, 30];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // Only entries older than the 10 unit window are swept, i.e. those before `25`.
+    assert_eq!(table.expire(UnixNanos(35)).unwrap(), 3);
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(by_time, vec![2, 4]);
+    let index = sled_table::Reader::<FooTimestampTable>::from(&t);
+    assert_eq!(index.iter().unwrap().count(), 2);
+    assert_eq!(table.expire(UnixNanos(35)).unwrap(), 0);
+
+    // A `now` so early that the cutoff would underflow expires nothing.
+    assert_eq!(table.expire(UnixNanos(::std::i64::MIN + 5)).unwrap(), 0);
+    assert_eq!(table.iter().unwrap().count(), 2);
+}
+
+#[test]
+fn test_set_preserving_timestamp() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    for (key, &ts) in [2, 4, 6].iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // The new value's timestamp is ignored in favour of the existing one.
+    let value = Foo { timestamp: UnixNanos(10), data: vec![42] };
+    table.set_preserving_timestamp(&0, &value).unwrap();
+    assert_eq!(table.get(&0).unwrap().unwrap(), Foo { timestamp: UnixNanos(2), data: vec![42] });
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(by_time, vec![0, 1, 2]);
+    let index = sled_table::Reader::<FooTimestampTable>::from(&t);
+    assert_eq!(index.iter().unwrap().count(), 3);
+
+    // Without an existing entry, the value's own timestamp is used.
+    table.set_preserving_timestamp(&3, &value).unwrap();
+    let by_time: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(by_time, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_iter_keys_by_time() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 1, 7];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    let keys: Vec<_> = table.iter_keys_by_time().unwrap().map(Result::unwrap).collect();
+    let expected: Vec<_> = table
+        .iter()
+        .unwrap()
+        .map(|res| res.unwrap())
+        .map(|(k, v)| (v.timestamp, k))
+        .collect();
+    assert_eq!(keys, expected);
+    assert_eq!(keys[0], (UnixNanos(1), 3));
+}
+
+#[test]
+fn test_count() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    assert_eq!(table.count().unwrap(), 0);
+    let timestamps = vec![9, 3, 5, 1, 7];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+    assert_eq!(table.count().unwrap(), timestamps.len());
+
+    // Moving an entry in time replaces its index entry rather than adding another.
+    table.set(&0, &Foo { timestamp: UnixNanos(11), data: vec![] }).unwrap();
+    assert_eq!(table.count().unwrap(), timestamps.len());
+    table.del(&1).unwrap();
+    assert_eq!(table.count().unwrap(), timestamps.len() - 1);
+}
+
+#[test]
+fn test_nth_by_time() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    assert_eq!(table.nth_by_time(0).unwrap(), None);
+    let timestamps = vec![9, 3, 5, 1, 7];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    let first = table.iter().unwrap().next().unwrap().unwrap();
+    assert_eq!(table.nth_by_time(0).unwrap(), Some(first));
+    let (key, value) = table.nth_by_time(2).unwrap().unwrap();
+    assert_eq!((key, value.timestamp), (2, UnixNanos(5)));
+    assert_eq!(table.nth_by_time(5).unwrap(), None);
+
+    // Stale index entries are not counted.
+    let index = sled_table::Writer::<FooTimestampTable>::from(&t);
+    index.set(&Key::from((UnixNanos(2), 0)), &()).unwrap();
+    let (key, value) = table.nth_by_time(2).unwrap().unwrap();
+    assert_eq!((key, value.timestamp), (2, UnixNanos(5)));
+    assert_eq!(table.nth_by_time(5).unwrap(), None);
+}
+
+#[test]
+fn test_earliest_and_latest() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    assert_eq!(table.earliest().unwrap(), None);
+    assert_eq!(table.latest().unwrap(), None);
+    let timestamps = vec![9, 3, 5, 3, 9, 7];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // Ties are broken by choosing the first and last entries in iteration order respectively.
+    let (key, value) = table.earliest().unwrap().unwrap();
+    assert_eq!((key, value.timestamp), (1, UnixNanos(3)));
+    let (key, value) = table.latest().unwrap().unwrap();
+    assert_eq!((key, value.timestamp), (4, UnixNanos(9)));
+    let last = table.iter().unwrap().last().unwrap().unwrap();
+    assert_eq!(table.latest().unwrap(), Some(last));
+}
+
+#[test]
+fn test_succ_and_pred_entries() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 3, 9, 7];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    fn entry(res: sled_table::Result<Option<(FooId, Foo)>>) -> Option<(FooId, UnixNanos)> {
+        res.unwrap().map(|(k, v)| (k, v.timestamp))
+    }
+
+    // Each entry lies at the boundary timestamp, with ties broken by choosing the smallest key
+    // for successors and the largest key for predecessors.
+    assert_eq!(entry(table.succ_incl_entry(UnixNanos(3))), Some((1, UnixNanos(3))));
+    assert_eq!(entry(table.succ_incl_entry(UnixNanos(4))), Some((2, UnixNanos(5))));
+    assert_eq!(entry(table.succ_entry(UnixNanos(7))), Some((0, UnixNanos(9))));
+    assert_eq!(entry(table.succ_entry(UnixNanos(9))), None);
+    assert_eq!(entry(table.pred_incl_entry(UnixNanos(9))), Some((4, UnixNanos(9))));
+    assert_eq!(entry(table.pred_incl_entry(UnixNanos(8))), Some((5, UnixNanos(7))));
+    assert_eq!(entry(table.pred_entry(UnixNanos(5))), Some((3, UnixNanos(3))));
+    assert_eq!(entry(table.pred_entry(UnixNanos(3))), None);
+
+    // Stale index entries are skipped rather than returned or used as the boundary.
+    let index = sled_table::Writer::<FooTimestampTable>::from(&t);
+    index.set(&Key::from((UnixNanos(4), 0)), &()).unwrap();
+    assert_eq!(entry(table.succ_incl_entry(UnixNanos(4))), Some((2, UnixNanos(5))));
+    assert_eq!(entry(table.succ_entry(UnixNanos(3))), Some((2, UnixNanos(5))));
+    assert_eq!(entry(table.pred_incl_entry(UnixNanos(4))), Some((3, UnixNanos(3))));
+    assert_eq!(entry(table.pred_entry(UnixNanos(5))), Some((3, UnixNanos(3))));
+}
+
+#[test]
+fn test_between() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 1, 7, 5, ::std::i64::MAX];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    let between = |start: i64, end: i64| -> Vec<FooId> {
+        let entries = table.between(UnixNanos(start), UnixNanos(end)).unwrap();
+        entries.into_iter().map(|(k, _)| k).collect()
+    };
+    // Entries exactly at both `start` and `end` are included.
+    assert_eq!(between(3, 7), vec![1, 2, 5, 4]);
+    assert_eq!(between(4, 6), vec![2, 5]);
+    assert_eq!(between(5, 5), vec![2, 5]);
+    assert!(between(10, 20).is_empty());
+    assert_eq!(between(9, ::std::i64::MAX), vec![0, 6]);
+}
+
+#[test]
+fn test_collect_range() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 1, 7, 5];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+    // A stale index entry that is not reflected by the table.
+    let index = sled_table::Writer::<FooTimestampTable>::from(&t);
+    index.set(&Key::from((UnixNanos(4), 0)), &()).unwrap();
+
+    let scanned = |iter: sled_table::timestamp::IterRange<FooTable>| -> Vec<(FooId, Foo)> {
+        iter.map(Result::unwrap).collect()
+    };
+    let collected = table.collect_range(UnixNanos(3)..UnixNanos(8)).unwrap();
+    assert_eq!(collected, scanned(table.scan_range(UnixNanos(3)..UnixNanos(8)).unwrap()));
+    assert_eq!(collected.iter().map(|&(k, _)| k).collect::<Vec<_>>(), vec![1, 2, 5, 4]);
+    let collected = table.collect_range(..).unwrap();
+    assert_eq!(collected, scanned(table.scan_range(..).unwrap()));
+    assert_eq!(collected.len(), 6);
+    assert!(table.collect_range(UnixNanos(10)..).unwrap().is_empty());
+}
+
+#[test]
+fn test_tail() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    assert!(table.tail(3).unwrap().is_empty());
+
+    let timestamps = vec![9, 3, 5, 1, 7];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+    let tail = |n: usize| -> Vec<(FooId, i64)> {
+        let entries = table.tail(n).unwrap();
+        entries.into_iter().map(|(k, v)| (k, v.timestamp.0)).collect()
+    };
+    assert_eq!(tail(3), vec![(0, 9), (4, 7), (2, 5)]);
+    assert_eq!(tail(10), vec![(0, 9), (4, 7), (2, 5), (1, 3), (3, 1)]);
+    assert!(tail(0).is_empty());
+}
+
+#[test]
+fn test_scan_range_rev() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 1, 7, 5];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    fn keys<I>(iter: I) -> Vec<FooId>
+    where
+        I: Iterator<Item = sled_table::Result<(FooId, Foo)>>,
+    {
+        iter.map(|res| res.unwrap().0).collect()
+    }
+    fn reversed(mut keys: Vec<FooId>) -> Vec<FooId> {
+        keys.reverse();
+        keys
+    }
+
+    let rev = keys(table.scan_range_rev(UnixNanos(3)..UnixNanos(7)).unwrap());
+    assert_eq!(rev, vec![5, 2, 1]);
+    let fwd = keys(table.scan_range(UnixNanos(3)..UnixNanos(7)).unwrap());
+    assert_eq!(rev, reversed(fwd));
+    let rev = keys(table.scan_range_rev(UnixNanos(5)..).unwrap());
+    let fwd = keys(table.scan_range(UnixNanos(5)..).unwrap());
+    assert_eq!(rev, reversed(fwd));
+    let rev = keys(table.scan_range_rev(..UnixNanos(5)).unwrap());
+    let fwd = keys(table.scan_range(..UnixNanos(5)).unwrap());
+    assert_eq!(rev, reversed(fwd));
+    let rev = keys(table.scan_range_rev(..).unwrap());
+    let fwd = keys(table.scan_range(..).unwrap());
+    assert_eq!(rev, reversed(fwd));
+    assert!(table.scan_range_rev(UnixNanos(10)..).unwrap().next().is_none());
+}
+
+#[test]
+fn test_range_keys() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 1, 7, 5];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    fn expected(iter: sled_table::timestamp::IterRange<FooTable>) -> Vec<(UnixNanos, FooId)> {
+        iter.map(|res| res.unwrap()).map(|(k, v)| (v.timestamp, k)).collect()
+    }
+    fn collect(iter: sled_table::timestamp::RangeKeys<FooTable>) -> Vec<(UnixNanos, FooId)> {
+        iter.map(Result::unwrap).collect()
+    }
+
+    let range = UnixNanos(3)..UnixNanos(7);
+    let keys = collect(table.range_keys(range.clone()).unwrap());
+    assert_eq!(keys, vec![(UnixNanos(3), 1), (UnixNanos(5), 2), (UnixNanos(5), 5)]);
+    assert_eq!(keys, expected(table.scan_range(range).unwrap()));
+    let keys = collect(table.range_keys(UnixNanos(5)..).unwrap());
+    assert_eq!(keys, expected(table.scan_range(UnixNanos(5)..).unwrap()));
+    let keys = collect(table.range_keys(..UnixNanos(5)).unwrap());
+    assert_eq!(keys, expected(table.scan_range(..UnixNanos(5)).unwrap()));
+    let keys = collect(table.range_keys(..).unwrap());
+    assert_eq!(keys, expected(table.scan_range(..).unwrap()));
+    assert!(table.range_keys(UnixNanos(10)..).unwrap().next().is_none());
+}
+
+#[test]
+fn test_iter_double_ended() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![9, 3, 5, 1, 7, 5, 2];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+    let fwd: Vec<_> = table.iter().unwrap().map(|res| res.unwrap().0).collect();
+    assert_eq!(fwd, vec![3, 6, 1, 2, 5, 4, 0]);
+
+    // Reversing yields the same entries in the opposite order.
+    let rev: Vec<_> = table.iter().unwrap().rev().map(|res| res.unwrap().0).collect();
+    assert_eq!(rev, fwd.iter().cloned().rev().collect::<Vec<_>>());
+
+    // Alternating ends meet in the middle without overlap.
+    let mut iter = table.iter().unwrap();
+    let (mut front, mut back) = (vec![], vec![]);
+    loop {
+        match iter.next() {
+            None => break,
+            Some(res) => front.push(res.unwrap().0),
+        }
+        match iter.next_back() {
+            None => break,
+            Some(res) => back.push(res.unwrap().0),
+        }
+    }
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+    back.reverse();
+    front.extend(back);
+    assert_eq!(front, fwd);
+
+    // Iterating from the back stops at the start of the scan.
+    let rev: Vec<_> = table.scan(UnixNanos(5)).unwrap().rev().map(|res| res.unwrap().0).collect();
+    assert_eq!(rev, vec![0, 4, 5, 2]);
+}
+
+#[test]
+fn test_window() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![12, -3, 10, 19, 41, -10];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    let windows: Vec<_> = table
+        .window(UnixNanos(10))
+        .unwrap()
+        .map(Result::unwrap)
+        .map(|(start, entries)| (start, entries.into_iter().map(|(k, _)| k).collect::<Vec<_>>()))
+        .collect();
+    let expected = vec![
+        (UnixNanos(-10), vec![5, 1]),
+        (UnixNanos(10), vec![2, 0, 3]),
+        (UnixNanos(40), vec![4]),
+    ];
+    assert_eq!(windows, expected);
+
+    // A zero `step` is rejected before iteration begins.
+    match table.window(UnixNanos(0)) {
+        Err(sled_table::Error::ZeroStep) => (),
+        Err(err) => panic!("expected `ZeroStep`, found {:?}", err),
+        Ok(_) => panic!("expected `ZeroStep`"),
+    };
+}
+
+#[test]
+fn test_window_near_min_timestamp() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let timestamps = vec![std::i64::MIN, std::i64::MIN + 1, std::i64::MIN + 9];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // The aligned start of the earliest window cannot be represented, so it starts at the minimum.
+    let windows: Vec<_> = table
+        .window(UnixNanos(10))
+        .unwrap()
+        .map(Result::unwrap)
+        .map(|(start, entries)| (start, entries.len()))
+        .collect();
+    let expected = vec![(UnixNanos(std::i64::MIN), 2), (UnixNanos(std::i64::MIN + 8), 1)];
+    assert_eq!(windows, expected);
+    assert_eq!(table.histogram(UnixNanos(10)).unwrap(), expected);
+}
+
+#[test]
+fn test_histogram() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    assert!(table.histogram(UnixNanos(10)).unwrap().is_empty());
+    let timestamps = vec![12, -3, 10, 19, 41, -10, 45, 49];
+    for (key, &ts) in timestamps.iter().enumerate() {
+        let value = Foo { timestamp: UnixNanos(ts), data: vec![key as u8] };
+        table.set(&(key as u8), &value).unwrap();
+    }
+
+    // Empty windows are skipped.
+    let expected = vec![(UnixNanos(-10), 2), (UnixNanos(10), 3), (UnixNanos(40), 3)];
+    assert_eq!(table.histogram(UnixNanos(10)).unwrap(), expected);
+
+    // The counts agree with `window`.
+    let windows: Vec<_> = table
+        .window(UnixNanos(10))
+        .unwrap()
+        .map(Result::unwrap)
+        .map(|(start, entries)| (start, entries.len()))
+        .collect();
+    assert_eq!(windows, expected);
+
+    // A zero `step` is rejected.
+    match table.histogram(UnixNanos(0)) {
+        Err(sled_table::Error::ZeroStep) => (),
+        other => panic!("expected `ZeroStep`, found {:?}", other),
+    };
+}
+
+#[test]
+fn test_succ_at_max_timestamp() {
+    let t = test_tree();
+    let table = sled_table::timestamp::Writer::<FooTable>::from(&t);
+    let max = UnixNanos(::std::i64::MAX);
+    let before_max = UnixNanos(::std::i64::MAX - 1);
+    table.set(&0, &Foo { timestamp: before_max, data: vec![] }).unwrap();
+    table.set(&1, &Foo { timestamp: max, data: vec![] }).unwrap();
+
+    assert_eq!(table.succ(before_max).unwrap(), Some(max));
+    assert_eq!(table.succ(max).unwrap(), None);
+    assert_eq!(before_max.checked_next(), Some(max));
+    assert_eq!(max.checked_next(), None);
+}